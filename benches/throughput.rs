@@ -0,0 +1,96 @@
+use core::hint::black_box;
+use core::str::FromStr;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use saudi_id::{Id, IdType};
+
+/// Throughput is reported as IDs per second.
+fn generation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generation");
+    group.throughput(Throughput::Elements(1));
+
+    group.bench_function("new citizen", |b| {
+        b.iter(|| Id::new(black_box(IdType::Citizen)));
+    });
+    group.bench_function("new resident", |b| {
+        b.iter(|| Id::new(black_box(IdType::Resident)));
+    });
+
+    group.finish();
+}
+
+fn validation(c: &mut Criterion) {
+    let digits = vec![1, 5, 6, 4, 4, 3, 7, 0, 9, 1];
+
+    let mut group = c.benchmark_group("validation");
+    group.throughput(Throughput::Elements(1));
+
+    group.bench_function("is_valid", |b| {
+        b.iter(|| saudi_id::is_valid(black_box(&digits)));
+    });
+    group.bench_function("try_from u32", |b| {
+        b.iter(|| Id::try_from(black_box(1_564_437_091_u32)));
+    });
+    group.bench_function("try_from vec", |b| {
+        b.iter(|| Id::try_from(black_box(digits.clone())));
+    });
+    group.bench_function("from_str", |b| {
+        b.iter(|| Id::from_str(black_box("1564437091")));
+    });
+    group.bench_function("is_valid_str", |b| {
+        b.iter(|| saudi_id::is_valid_str(black_box("1564437091")));
+    });
+
+    group.finish();
+}
+
+fn formatting(c: &mut Criterion) {
+    let id = Id::new(IdType::Citizen);
+
+    let mut group = c.benchmark_group("formatting");
+    group.throughput(Throughput::Elements(1));
+
+    group.bench_function("to_string", |b| {
+        b.iter(|| black_box(&id).to_string());
+    });
+    group.bench_function("grouped", |b| {
+        b.iter(|| format!("{:#}", black_box(&id)));
+    });
+
+    group.finish();
+}
+
+/// Validating a batch of strings, like a job reading IDs from a file.
+fn bulk_validation(c: &mut Criterion) {
+    const BATCH_SIZE: usize = 10_000;
+
+    let ids: Vec<String> = Id::random_iter(IdType::Citizen)
+        .take(BATCH_SIZE)
+        .map(String::from)
+        .collect();
+
+    let mut group = c.benchmark_group("bulk validation");
+    group.throughput(Throughput::Elements(BATCH_SIZE as u64));
+
+    group.bench_function("from_str", |b| {
+        b.iter(|| {
+            black_box(&ids)
+                .iter()
+                .filter(|id| Id::from_str(id).is_ok())
+                .count()
+        });
+    });
+    group.bench_function("is_valid_str", |b| {
+        b.iter(|| {
+            black_box(&ids)
+                .iter()
+                .filter(|id| saudi_id::is_valid_str(id))
+                .count()
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, generation, validation, formatting, bulk_validation);
+criterion_main!(benches);
@@ -1,7 +1,7 @@
 use saudi_id::{Id, IdType};
 
 fn main() {
-    let id = Id::new(&IdType::Citizen);
+    let id = Id::new(IdType::Citizen);
 
     println!("{id}");
 }
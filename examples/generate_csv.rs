@@ -0,0 +1,11 @@
+use saudi_id::{Id, IdType};
+
+fn main() {
+    println!("id,type");
+
+    for id_type in [IdType::Citizen, IdType::Resident] {
+        for id in Id::random_iter(id_type).take(5) {
+            println!("{id},{}", id_type.name());
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use saudi_id::{Id, IdType};
 
-fn main() {
-    let id = Id::try_from(1_564_437_091);
+fn parse(input: &str) -> saudi_id::Result<Id> {
+    input.parse()
+}
 
-    match id {
+fn main() {
+    match parse("1564437091") {
         Ok(id) => match id.get_type() {
             IdType::Citizen => {
                 println!("Valid Citizen ID");
@@ -11,9 +13,12 @@ fn main() {
             IdType::Resident => {
                 println!("Valid Resident ID");
             }
+            _ => {
+                println!("Valid ID of another type");
+            }
         },
-        Err(_) => {
-            println!("Invalid ID");
+        Err(err) => {
+            println!("Invalid ID: {err}");
         }
     }
 }
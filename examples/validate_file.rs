@@ -0,0 +1,38 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("usage: validate_file PATH");
+        return ExitCode::from(2);
+    };
+    let file = match File::open(&path) {
+        Ok(file) => file,
+        Err(err) => {
+            eprintln!("failed to open {path}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut all_valid = true;
+    for (number, item) in saudi_id::validate_lines(BufReader::new(file)).enumerate() {
+        match item {
+            Ok((_, Ok(_))) => {}
+            Ok((line, Err(err))) => {
+                println!("{path}:{}: {line:?}: {err}", number + 1);
+                all_valid = false;
+            }
+            Err(err) => {
+                println!("{path}:{}: {err}", number + 1);
+                all_valid = false;
+            }
+        }
+    }
+
+    if all_valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
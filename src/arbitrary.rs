@@ -0,0 +1,129 @@
+//! [`proptest`] and [`quickcheck`] support, enabled by the features with the same names.
+//!
+//! Only valid IDs are generated, and shrinking keeps the prefix and moves towards the lowest ID of the same type.
+
+#[cfg(feature = "quickcheck")]
+use alloc::boxed::Box;
+
+#[cfg(any(test, feature = "proptest"))]
+use proptest::prelude::*;
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, Gen};
+
+#[cfg(feature = "quickcheck")]
+use crate::ID_SIZE;
+use crate::{Id, IdType, IDS_PER_TYPE};
+
+/// A strategy for valid IDs of both types.
+#[cfg(any(test, feature = "proptest"))]
+pub fn arb_id() -> impl Strategy<Value = Id> {
+    prop_oneof![
+        arb_id_of_type(IdType::Citizen),
+        arb_id_of_type(IdType::Resident),
+    ]
+}
+
+/// A strategy for valid IDs of `id_type`.
+#[cfg(any(test, feature = "proptest"))]
+pub fn arb_id_of_type(id_type: IdType) -> impl Strategy<Value = Id> {
+    (0..IDS_PER_TYPE).prop_map(move |n| Id::from_payload_number(id_type, n))
+}
+
+#[cfg(feature = "quickcheck")]
+impl Arbitrary for IdType {
+    fn arbitrary(g: &mut Gen) -> Self {
+        if bool::arbitrary(g) {
+            Self::Citizen
+        } else {
+            Self::Resident
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match *self {
+            Self::Citizen => quickcheck::empty_shrinker(),
+            Self::Resident => quickcheck::single_shrinker(Self::Citizen),
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl Arbitrary for Id {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::from_payload_number(IdType::arbitrary(g), u32::arbitrary(g) % IDS_PER_TYPE)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let id_type = self.get_type();
+        let n = self.digits[1..ID_SIZE - 1]
+            .iter()
+            .fold(0, |acc, &digit| acc * 10 + u32::from(digit));
+
+        Box::new(
+            n.shrink()
+                .map(move |n| Self::from_payload_number(id_type, n)),
+        )
+    }
+}
+
+#[expect(clippy::allow_attributes_without_reason)]
+#[expect(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "proptest")]
+    proptest! {
+        #[test]
+        fn generates_valid_ids(id in arb_id()) {
+            prop_assert!(crate::is_valid(id.digits()));
+            prop_assert_eq!(Id::try_from(id.to_u64()).unwrap(), id);
+        }
+
+        #[test]
+        fn generates_ids_of_type(id in arb_id_of_type(IdType::Resident)) {
+            prop_assert_eq!(id.get_type(), IdType::Resident);
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn generates_both_types() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        let mut runner = TestRunner::deterministic();
+        let types = (0..64_u32)
+            .map(|_| arb_id().new_tree(&mut runner).unwrap().current().get_type())
+            .collect::<Vec<_>>();
+
+        assert!(types.contains(&IdType::Citizen));
+        assert!(types.contains(&IdType::Resident));
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck_generates_valid_ids() {
+        fn prop(id: Id) -> bool {
+            crate::is_valid(&id.into_digits())
+        }
+
+        quickcheck::quickcheck(prop as fn(Id) -> bool);
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck_shrinks_to_valid_lower_ids() {
+        let id = Id::try_from(2_581_872_351_u32).unwrap();
+        let shrunk = id.shrink().collect::<Vec<_>>();
+
+        assert!(!shrunk.is_empty());
+        assert!(shrunk.iter().all(|smaller| smaller < &id));
+        assert!(shrunk
+            .iter()
+            .all(|smaller| smaller.get_type() == IdType::Resident
+                && crate::is_valid(smaller.digits())));
+        assert_eq!(shrunk[0], Id::try_from(2_000_000_006_u32).unwrap());
+    }
+}
@@ -0,0 +1,93 @@
+//! C functions for validating IDs, enabled by the `ffi` feature.
+//!
+//! ```c
+//! #include <stdbool.h>
+//! #include <stdint.h>
+//!
+//! bool saudi_id_validate(const char *id);
+//! int32_t saudi_id_type(const char *id);
+//! ```
+//!
+//! Strings are parsed like [`FromStr`](core::str::FromStr) after checking that they are UTF-8, and a null pointer is
+//! treated as an invalid ID. Nothing is allocated or kept after returning, so there is nothing to free.
+
+use core::ffi::{c_char, CStr};
+
+use crate::Id;
+
+/// Check if the null-terminated string at `id` is a valid ID, `false` for a null pointer.
+///
+/// # Safety
+///
+/// `id` must be null, or point to a null-terminated string that stays valid and unchanged during the call.
+#[no_mangle]
+pub unsafe extern "C" fn saudi_id_validate(id: *const c_char) -> bool {
+    // SAFETY: Same requirements as this function.
+    unsafe { to_str(id) }.is_some_and(crate::is_valid_str)
+}
+
+/// The type prefix of the null-terminated string at `id`, 1 for citizen and 2 for resident, or 0 if it is not a valid
+/// ID or a null pointer.
+///
+/// # Safety
+///
+/// `id` must be null, or point to a null-terminated string that stays valid and unchanged during the call.
+#[no_mangle]
+pub unsafe extern "C" fn saudi_id_type(id: *const c_char) -> i32 {
+    // SAFETY: Same requirements as this function.
+    unsafe { to_str(id) }
+        .and_then(|s| s.parse::<Id>().ok())
+        .map_or(0, |id| i32::from(id.get_type().prefix()))
+}
+
+/// # Safety
+///
+/// `ptr` must be null, or point to a null-terminated string that stays valid and unchanged for `'ptr`.
+unsafe fn to_str<'ptr>(ptr: *const c_char) -> Option<&'ptr str> {
+    if ptr.is_null() {
+        return None;
+    }
+
+    // SAFETY: Not null, and the rest is guaranteed by the caller.
+    unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ptr;
+
+    use super::*;
+
+    fn validate(id: &CStr) -> bool {
+        // SAFETY: A `CStr` is null-terminated and borrowed during the call.
+        unsafe { saudi_id_validate(id.as_ptr()) }
+    }
+
+    fn id_type(id: &CStr) -> i32 {
+        // SAFETY: A `CStr` is null-terminated and borrowed during the call.
+        unsafe { saudi_id_type(id.as_ptr()) }
+    }
+
+    #[test]
+    fn validate_c_strings() {
+        assert!(validate(c"1564437091"));
+        assert!(!validate(c"1564437092"));
+        assert!(!validate(c""));
+    }
+
+    #[test]
+    fn type_of_c_strings() {
+        assert_eq!(id_type(c"1564437091"), 1_i32);
+        assert_eq!(id_type(c"2000000006"), 2_i32);
+        assert_eq!(id_type(c"3581872359"), 0_i32);
+        assert_eq!(id_type(c"\xff"), 0_i32);
+    }
+
+    #[test]
+    fn null_pointers() {
+        // SAFETY: Null pointers are allowed.
+        assert!(!unsafe { saudi_id_validate(ptr::null()) });
+        // SAFETY: Null pointers are allowed.
+        assert_eq!(unsafe { saudi_id_type(ptr::null()) }, 0_i32);
+    }
+}
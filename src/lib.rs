@@ -1,13 +1,70 @@
 //! Parse (validate) from multible data types or generate new random [Saudi Arabian national IDs](https://en.wikipedia.org/wiki/Saudi_Arabian_identity_card).
 //!
 //! Used to validate IDs and find thier type (Citizen or Resident), or used to test software by generating random valid IDs.
+//!
+//! # Features
+//!
+//! - `std` (default): generate random IDs with [`Id::new`]. Without it the crate is `no_std`, but still needs `alloc`.
+//! - `rand`: generate random IDs from any [`rand::Rng`], e.g. a seeded one, with [`Id::new_with_rng`] and
+//!   [`Id::generate_unique_with_rng`], also under `no_std`.
+//! - `serde`: `Serialize` and `Deserialize` for [`Id`] (as its digits string) and [`IdType`].
+//! - `cli`: the `saudi_id` binary, to validate (`saudi_id validate 1564437091`) and generate
+//!   (`saudi_id generate --type resident --count 5`) IDs from the command line. Without arguments it prints
+//!   `VALID <type>` or `INVALID` for each line of stdin, and `saudi_id --generate citizen 5` also generates IDs.
+//! - `ffi`: [`ffi`] module of C functions for validating IDs.
+//! - `proptest`: [`arb_id`] and [`arb_id_of_type`] strategies that only generate valid IDs.
+//! - `quickcheck`: `quickcheck::Arbitrary` for [`Id`] and [`IdType`], only generating valid IDs.
+//! - `subtle`: [`Id::validate_ct`] to validate digits in constant time.
+//! - `wasm`: [`wasm`] module of `wasm-bindgen` functions for validating and generating IDs from JavaScript.
+//! - `zeroize` (opt-in): `ZeroizeOnDrop` for [`Id`], to clear its digits from memory when dropped. There is no
+//!   `Zeroize`, since a zeroized `Id` would be invalid. Digits taken out with [`Id::into_digits`] or by iterating an
+//!   owned `Id` are not wiped.
+//!
+//! # Prefixes
+//!
+//! Only the known prefixes, 1 for citizens and 2 for residents, are valid. Any other prefix fails with
+//! [`ParseError::InvalidPrefix`], even if the ID passes the Luhn Algorithm. [`IdType`] is `#[non_exhaustive]`, so new
+//! prefixes can be added without a breaking change. To accept IDs with prefixes that are not known yet, use
+//! [`classify`], which only checks the length, the digits and the Luhn Algorithm.
+//!
+//! For validation only, disable the default features with `saudi_id = { version = "0.2", default-features = false }`.
+//! Then `rand` is not a dependency, and random generation (`Id::new`, `Id::new_with_prefix`, `Id::from_template`,
+//! `Id::generate_unique`, `Id::random_iter` and their `_with_rng` versions) and `validate_lines` are not compiled.
+//! Parsing and validation stay the same, as do [`DigitSource`], [`Id::new_from_source`], [`Id::from_payload`],
+//! [`IdBuilder`] and [`compute_check_digit`], which don't need randomness.
 
-// TODO: Support no_std.
+#![cfg_attr(not(test), no_std)]
 
-extern crate luhnr;
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-#[derive(PartialEq, Eq, Debug)]
+#[cfg(feature = "rand")]
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::fmt::Write;
+
+// NOTE: Also compiled for tests, which use the proptest strategies even without the `proptest` feature.
+#[cfg(any(test, feature = "proptest", feature = "quickcheck"))]
+mod arbitrary;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "proptest")]
+pub use arbitrary::{arb_id, arb_id_of_type};
+
+/// Defaults to [`IdType::Citizen`], the lower prefix.
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
 pub enum IdType {
+    #[default]
     Citizen = 1,
     Resident = 2,
 }
@@ -17,189 +74,2724 @@ impl IdType {
     pub const fn prefix(self) -> u8 {
         self as u8
     }
+
+    /// The type of IDs that start with `prefix`, if any.
+    #[must_use]
+    pub const fn from_prefix(prefix: u8) -> Option<Self> {
+        match prefix {
+            CITIZEN_PREFIX => Some(Self::Citizen),
+            RESIDENT_PREFIX => Some(Self::Resident),
+            _ => None,
+        }
+    }
+
+    /// Lowercase name of the type, `"citizen"` or `"resident"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Citizen => "citizen",
+            Self::Resident => "resident",
+        }
+    }
+
+    /// The type with this [`IdType::name`], ignoring ASCII case.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::Citizen, Self::Resident]
+            .into_iter()
+            .find(|id_type| name.eq_ignore_ascii_case(id_type.name()))
+    }
 }
 
-#[derive(PartialEq, Eq, Debug)]
+/// A source of random digits for [`Id::new_from_source`]. With the `rand` feature, wrap any [`rand::RngCore`] in
+/// [`RngDigits`] to use it as one.
+pub trait DigitSource {
+    /// The next digit, only the remainder of dividing it by 10 is used.
+    fn next_digit(&mut self) -> u8;
+}
+
+/// [`DigitSource`] drawing uniform digits from a [`rand::RngCore`].
+// NOTE: A wrapper instead of a blanket impl, so other crates can still implement `DigitSource` for their own RNGs
+// when any crate enables the `rand` feature.
+#[cfg(feature = "rand")]
+#[derive(Clone, Debug)]
+pub struct RngDigits<R>(pub R);
+
+#[cfg(feature = "rand")]
+impl<R: rand::RngCore> DigitSource for RngDigits<R> {
+    fn next_digit(&mut self) -> u8 {
+        rand::Rng::gen_range(&mut self.0, 0..10)
+    }
+}
+
+/// Citizen or resident with equal probability, so `rng.gen::<Id>()` is a random ID of either type.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<IdType> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> IdType {
+        if rng.gen() {
+            IdType::Citizen
+        } else {
+            IdType::Resident
+        }
+    }
+}
+
+/// A random ID, citizen or resident with equal probability.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Id> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Id {
+        Id::new_with_rng(self.sample(rng), rng)
+    }
+}
+
+/// A random ID of this type, e.g. `rng.sample(IdType::Resident)`.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Id> for IdType {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Id {
+        Id::new_with_rng(*self, rng)
+    }
+}
+
+// NOTE: Comparing the digits lexicographically is the same as comparing the numbers, since all IDs have 10 digits.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct Id {
-    pub digits: Vec<u8>,
+    // NOTE: Private, so every `Id` is guaranteed to be valid.
+    digits: Vec<u8>,
 }
 
-#[derive(Debug)]
+#[derive(PartialEq, Eq, Debug)]
 pub enum ParseError {
-    InvalidId,
+    /// Not exactly 10 digits.
+    WrongLength { got: usize },
+    /// A prefix to generate an ID from is empty or has more than 9 digits.
+    WrongPrefixLength { got: usize },
+    /// An [`IdBuilder`] was finished with other than 9 or 10 digits.
+    WrongBuilderLength { got: usize },
+    /// First digit is not 1 or 2.
+    InvalidPrefix { got: u8 },
+    /// Check digit doesn't match the Luhn Algorithm.
+    FailedLuhn,
+    /// A character that is not a digit, or a number greater than 9, at the given (zero-based) position.
+    NonDigitCharacter { position: usize },
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::WrongLength { got } => {
+                write!(f, "invalid Saudi ID: expected {ID_SIZE} digits, got {got}")
+            }
+            Self::WrongPrefixLength { got } => write!(
+                f,
+                "invalid Saudi ID: prefix must have 1 to {} digits, got {got}",
+                ID_SIZE - 1
+            ),
+            Self::WrongBuilderLength { got } => write!(
+                f,
+                "invalid Saudi ID: expected {} or {ID_SIZE} digits, got {got}",
+                ID_SIZE - 1
+            ),
+            Self::InvalidPrefix { got } => write!(
+                f,
+                "invalid Saudi ID: first digit must be {CITIZEN_PREFIX} or {RESIDENT_PREFIX}, got {got}"
+            ),
+            Self::FailedLuhn => write!(f, "invalid Saudi ID: failed Luhn validation"),
+            Self::NonDigitCharacter { position } => write!(
+                f,
+                "invalid Saudi ID: non-digit character at position {position}"
+            ),
+        }
+    }
+}
+
+// NOTE: `core::error::Error` is the same trait as `std::error::Error`, so this keeps working under no_std.
+impl core::error::Error for ParseError {}
+
+/// [`core::result::Result`] that fails with a [`ParseError`] by default.
+pub type Result<T, E = ParseError> = core::result::Result<T, E>;
+
+/// The operating system failed to provide randomness for [`Id::try_new`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct GenError(rand::Error);
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for GenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "failed to generate Saudi ID: {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for GenError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// Facts derived from the digits of an [`Id`], returned by [`Id::metadata`].
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Metadata {
+    pub id_type: IdType,
+    /// Same as [`Id::to_u64`].
+    pub value: u64,
 }
 
-const ID_SIZE: usize = 10;
+/// Type of an ID by its prefix, returned by [`classify`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Classification {
+    /// Prefix of an [`IdType`].
+    Known(IdType),
+    /// Any other prefix (including 0), which might be used by future types of IDs.
+    Unknown(u8),
+}
+
+/// The number of digits in every ID.
+pub const ID_SIZE: usize = 10;
 const CITIZEN_PREFIX: u8 = IdType::Citizen.prefix();
 const RESIDENT_PREFIX: u8 = IdType::Resident.prefix();
+/// Digit indexes that start a new group when formatting for readability: the prefix, then three groups of three.
+const GROUP_STARTS: [usize; 3] = [1, 4, 7];
+/// Number of possible IDs of each type, only 8 digits are free between the prefix and the check digit.
+const IDS_PER_TYPE: u32 = 100_000_000;
+/// Separators skipped by [`FromStr`](core::str::FromStr), in addition to ASCII whitespace.
+const DEFAULT_SEPARATORS: &[char] = &['-'];
 
-impl Id {
-    /// Create a new random ID
-    #[expect(clippy::missing_panics_doc, reason = "Never panics")]
-    #[must_use]
-    pub fn new(id_type: &IdType) -> Self {
-        match *id_type {
-            IdType::Citizen => {
-                #[expect(clippy::unwrap_used, reason = "Arguments always valid")]
-                let digits = luhnr::generate_with_prefix(ID_SIZE, &[CITIZEN_PREFIX]).unwrap();
+const ARABIC_INDIC_ZERO: char = '\u{660}';
+
+/// Numeric value of an ASCII (`0`-`9`), Arabic-Indic (`٠`-`٩`) or Extended Arabic-Indic (`۰`-`۹`) digit.
+#[expect(clippy::cast_possible_truncation, reason = "Always less than 10")]
+const fn digit_value(c: char) -> Option<u8> {
+    let zero = match c {
+        '0'..='9' => '0',
+        '\u{660}'..='\u{669}' => ARABIC_INDIC_ZERO,
+        '\u{6F0}'..='\u{6F9}' => '\u{6F0}',
+        _ => return None,
+    };
+
+    Some((c as u32 - zero as u32) as u8)
+}
+
+/// Digit that makes `payload` followed by it pass the Luhn Algorithm.
+const fn luhn_check_digit(payload: &[u8]) -> u8 {
+    let mut sum: u32 = 0;
+    let mut double = true;
+    let mut i = payload.len();
 
-                Self { digits }
+    // Starting from the right, double every other digit and sum the digits of the result.
+    while i > 0 {
+        i -= 1;
+
+        let mut value = payload[i] as u32;
+        if double {
+            value *= 2;
+            if value > 9 {
+                value -= 9;
             }
-            IdType::Resident => {
-                #[expect(clippy::unwrap_used, reason = "Arguments always valid")]
-                let digits = luhnr::generate_with_prefix(ID_SIZE, &[RESIDENT_PREFIX]).unwrap();
+        }
+
+        sum += value;
+        double = !double;
+    }
+
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// Characters skipped by [`FromStr`](core::str::FromStr).
+fn is_default_separator(c: char) -> bool {
+    c.is_ascii_whitespace() || DEFAULT_SEPARATORS.contains(&c)
+}
+
+/// The digits of `s` parsed like [`FromStr`](core::str::FromStr), where a `-` before the first digit is a sign and not
+/// a separator.
+fn parse_default(s: &str) -> Result<[u8; ID_SIZE]> {
+    if let Some((position, '-')) = s
+        .chars()
+        .enumerate()
+        .find(|&(_, c)| !c.is_ascii_whitespace())
+    {
+        return Err(ParseError::NonDigitCharacter { position });
+    }
+
+    Id::parse_digits(s, is_default_separator)
+}
+
+/// Compute the Luhn check digit (the last digit of an ID) for the first 9 digits, each one from 0 to 9.
+#[must_use]
+pub const fn compute_check_digit(payload: &[u8; ID_SIZE - 1]) -> u8 {
+    luhn_check_digit(payload)
+}
+
+/// The number of valid IDs of `id_type`, the size of the space [`Id::generate_unique`] picks from.
+///
+/// The first digit is fixed by the type and the last one is computed from the others, leaving 8 free digits, so
+/// there are 10^8 IDs of each type.
+#[must_use]
+pub const fn valid_count(id_type: IdType) -> u64 {
+    // NOTE: Matched so a new type has to decide its count, even though all current types have the same one.
+    match id_type {
+        IdType::Citizen | IdType::Resident => IDS_PER_TYPE as u64,
+    }
+}
+
+/// Same as [`is_valid`] for exactly 10 digits, but usable in `const` context, e.g. to check hard-coded IDs at compile
+/// time with `const _: () = assert!(validate_const(&[1, 5, 6, 4, 4, 3, 7, 0, 9, 1]));`.
+///
+/// Takes an array instead of a slice and only returns a `bool`, so a failing `const` assertion doesn't tell which
+/// check failed, use [`Id::try_from`] for that.
+#[must_use]
+pub const fn validate_const(digits: &[u8; ID_SIZE]) -> bool {
+    let mut i = 0;
+    while i < ID_SIZE {
+        if digits[i] > 9 {
+            return false;
+        }
+        i += 1;
+    }
+
+    let Some((&check, payload)) = digits.split_last() else {
+        return false;
+    };
+
+    IdType::from_prefix(digits[0]).is_some() && luhn_check_digit(payload) == check
+}
+
+/// Check if `digits` are a valid ID (length, digit range, prefix and Luhn), without allocating.
+#[must_use]
+pub fn is_valid(digits: &[u8]) -> bool {
+    Id::validate(digits).is_ok()
+}
+
+/// Check if `s` is a valid ID, parsed like [`FromStr`](core::str::FromStr), without allocating.
+#[must_use]
+pub fn is_valid_str(s: &str) -> bool {
+    parse_default(s).is_ok_and(|digits| Id::validate_array(&digits).is_ok())
+}
+
+/// Parse `s` like [`FromStr`](core::str::FromStr), but return the raw prefix of IDs that only fail because of it,
+/// instead of [`ParseError::InvalidPrefix`].
+///
+/// # Errors
+///
+/// 1. Not using the Luhn Algorithm.
+/// 2. More or less then 10 digits.
+/// 3. A character that is not a digit.
+pub fn classify(s: &str) -> Result<Classification> {
+    let digits = parse_default(s)?;
+    let [prefix, .., check] = digits;
+
+    if luhn_check_digit(&digits[..ID_SIZE - 1]) != check {
+        return Err(ParseError::FailedLuhn);
+    }
 
-                Self { digits }
+    Ok(IdType::from_prefix(prefix).map_or(Classification::Unknown(prefix), Classification::Known))
+}
+
+/// Find all valid IDs in `text`, as runs of exactly 10 digits between other characters.
+#[must_use]
+pub fn find_all(text: &str) -> Vec<Id> {
+    let mut ids = Vec::new();
+    let mut digits = [0; ID_SIZE];
+    let mut count = 0;
+
+    // A trailing `None` ends the last run.
+    for digit in text.chars().map(digit_value).chain(core::iter::once(None)) {
+        if let Some(digit) = digit {
+            if let Some(slot) = digits.get_mut(count) {
+                *slot = digit;
+            }
+            count += 1;
+        } else {
+            if count == ID_SIZE && Id::validate_array(&digits).is_ok() {
+                ids.push(Id {
+                    digits: Vec::from(digits),
+                });
             }
+            count = 0;
         }
     }
 
-    fn validate(digits: &[u8]) -> bool {
-        // NOTE: The second statment is less likely to fail, but it depends on your usage.
-        luhnr::validate(digits) && digits.len() == ID_SIZE
+    ids
+}
+
+/// Parse each line of `reader` like [`FromStr`](core::str::FromStr), yielding it with its result, without reading
+/// everything into memory.
+///
+/// A line that is not valid UTF-8 is yielded as an [`InvalidData`](std::io::ErrorKind::InvalidData) error, and the
+/// following lines are still read. A read error is yielded as is, and ends the iterator.
+#[cfg(feature = "std")]
+pub fn validate_lines<R: std::io::BufRead>(
+    mut reader: R,
+) -> impl Iterator<Item = std::io::Result<(String, Result<Id>)>> {
+    let mut bytes = Vec::new();
+    let mut failed = false;
+
+    core::iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+
+        bytes.clear();
+        match reader.read_until(b'\n', &mut bytes) {
+            Ok(0) => None,
+            Ok(_) => {
+                // NOTE: Line endings are removed like `BufRead::lines` does.
+                if bytes.last() == Some(&b'\n') {
+                    bytes.pop();
+                    if bytes.last() == Some(&b'\r') {
+                        bytes.pop();
+                    }
+                }
+
+                Some(
+                    String::from_utf8(core::mem::take(&mut bytes))
+                        .map(|line| {
+                            let result = line.parse();
+
+                            (line, result)
+                        })
+                        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err)),
+                )
+            }
+            Err(err) => {
+                failed = true;
+
+                Some(Err(err))
+            }
+        }
+    })
+}
+
+impl Id {
+    /// Check if `digits` are a valid ID like [`is_valid`], in time that only depends on `digits.len()`.
+    ///
+    /// Every digit goes through the same range, prefix and Luhn operations using [`subtle`], without branching or
+    /// returning early on their values, so timing doesn't reveal which check failed or which digits are wrong.
+    #[cfg(feature = "subtle")]
+    #[must_use]
+    pub fn validate_ct(digits: &[u8]) -> bool {
+        use subtle::{ConstantTimeEq, ConstantTimeGreater};
+
+        let Ok(digits) = <&[u8; ID_SIZE]>::try_from(digits) else {
+            return false;
+        };
+
+        let mut valid = digits[0].ct_eq(&CITIZEN_PREFIX) | digits[0].ct_eq(&RESIDENT_PREFIX);
+        let mut sum = 0_u32;
+
+        for (i, &digit) in digits.iter().rev().enumerate() {
+            valid &= !digit.ct_gt(&9);
+
+            let value = u32::from(digit) << (i % 2);
+            sum += value - 9 * u32::from(value.ct_gt(&9).unwrap_u8());
+        }
+
+        bool::from(valid & (sum % 10).ct_eq(&0))
     }
 
-    #[expect(clippy::missing_panics_doc, reason = "Never panics")]
+    /// Create a new random ID
+    ///
+    /// # Panics
+    ///
+    /// Only if the operating system fails to provide randomness the first time it's used by a thread, use
+    /// [`Id::try_new`] to handle that instead.
+    #[cfg(feature = "std")]
     #[must_use]
-    pub fn get_type(&self) -> IdType {
-        #[expect(clippy::unwrap_used, reason = "Vec always has first digit")]
-        match *self.digits.first().unwrap() {
-            CITIZEN_PREFIX => IdType::Citizen,
-            RESIDENT_PREFIX => IdType::Resident,
-            _ => unreachable!(),
+    pub fn new(id_type: IdType) -> Self {
+        Self::new_with_rng(id_type, &mut rand::thread_rng())
+    }
+
+    /// Same as [`Id::new`], but returns an error instead of panicking when the operating system fails to provide
+    /// randomness.
+    ///
+    /// # Errors
+    ///
+    /// 1. Failed to seed a random number generator from the operating system.
+    #[cfg(feature = "std")]
+    pub fn try_new(id_type: IdType) -> Result<Self, GenError> {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::from_rng(rand::rngs::OsRng).map_err(GenError)?;
+
+        Ok(Self::new_with_rng(id_type, &mut rng))
+    }
+
+    /// Create a new random citizen ID, same as `Id::new(IdType::Citizen)`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn new_citizen() -> Self {
+        Self::new(IdType::Citizen)
+    }
+
+    /// Create a new random resident ID, same as `Id::new(IdType::Resident)`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn new_resident() -> Self {
+        Self::new(IdType::Resident)
+    }
+
+    /// Create a new random ID using `rng`, e.g. a seeded one for reproducible IDs.
+    #[cfg(feature = "rand")]
+    #[must_use]
+    pub fn new_with_rng<R: rand::Rng + ?Sized>(id_type: IdType, rng: &mut R) -> Self {
+        Self::new_from_source(id_type, &mut RngDigits(rng))
+    }
+
+    /// Create a new ID from the next 8 digits of `source`, computing the check digit, without depending on `rand`.
+    #[must_use]
+    pub fn new_from_source<S: DigitSource + ?Sized>(id_type: IdType, source: &mut S) -> Self {
+        let mut digits = [0; ID_SIZE];
+
+        digits[0] = id_type.prefix();
+        for digit in &mut digits[1..ID_SIZE - 1] {
+            *digit = source.next_digit() % 10;
         }
+
+        Self::complete(digits)
     }
-}
 
-impl TryFrom<u32> for Id {
-    type Error = ParseError;
+    /// Create a new random ID that starts with the `prefix` digits, see [`Id::new_with_prefix_and_rng`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Id::new_with_prefix_and_rng`].
+    #[cfg(feature = "std")]
+    pub fn new_with_prefix(prefix: &[u8]) -> Result<Self> {
+        Self::new_with_prefix_and_rng(prefix, &mut rand::thread_rng())
+    }
 
+    /// Create a new random ID that starts with the `prefix` digits using `rng`, the remaining digits are random
+    /// except for the check digit.
+    ///
     /// # Errors
     ///
-    /// 1. Not using the Luhn Algorithm.
-    /// 2. More or less then 10 digits.
-    /// 3. First digit is not 1 or 2.
-    fn try_from(mut id: u32) -> Result<Self, Self::Error> {
-        let mut digits: Vec<u8> = Vec::with_capacity(ID_SIZE);
+    /// 1. Prefix is empty or has more than 9 digits.
+    /// 2. First digit is not 1 or 2.
+    /// 3. A digit is greater than 9.
+    #[cfg(feature = "rand")]
+    pub fn new_with_prefix_and_rng<R: rand::Rng + ?Sized>(
+        prefix: &[u8],
+        rng: &mut R,
+    ) -> Result<Self> {
+        let Some(&first) = prefix.first().filter(|_| prefix.len() < ID_SIZE) else {
+            return Err(ParseError::WrongPrefixLength { got: prefix.len() });
+        };
 
-        while id > 0 {
-            digits.insert(0, (id % 10) as u8);
-            id /= 10;
+        if let Some(position) = prefix.iter().position(|&digit| digit > 9) {
+            return Err(ParseError::NonDigitCharacter { position });
+        }
+        if IdType::from_prefix(first).is_none() {
+            return Err(ParseError::InvalidPrefix { got: first });
         }
 
-        // Validate ID
-        if Self::validate(&digits) {
-            Ok(Self { digits })
-        } else {
-            Err(ParseError::InvalidId)
+        let mut digits = [0; ID_SIZE];
+
+        digits[..prefix.len()].copy_from_slice(prefix);
+        for digit in &mut digits[prefix.len()..ID_SIZE - 1] {
+            *digit = rng.gen_range(0..10);
         }
+
+        Ok(Self::complete(digits))
     }
-}
 
-impl TryFrom<Vec<u8>> for Id {
-    type Error = ParseError;
+    /// Create a new random ID from a template, see [`Id::from_template_with_rng`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Id::from_template_with_rng`].
+    #[cfg(feature = "std")]
+    pub fn from_template(template: &[Option<u8>; ID_SIZE]) -> Result<Self> {
+        Self::from_template_with_rng(template, &mut rand::thread_rng())
+    }
 
+    /// Create a new ID keeping the `Some` digits of `template` and filling the `None` ones randomly using `rng`.
+    ///
+    /// The last `None` digit (the check digit if it's `None`) is not random, but the only one that makes the ID
+    /// valid, and a fully fixed template is only validated.
+    ///
     /// # Errors
     ///
-    /// 1. Not using the Luhn Algorithm.
-    /// 2. More or less then 10 digits.
-    /// 3. First digit is not 1 or 2.
-    fn try_from(digits: Vec<u8>) -> Result<Self, Self::Error> {
-        // Validate ID
-        if Self::validate(&digits) {
-            Ok(Self { digits })
-        } else {
-            Err(ParseError::InvalidId)
+    /// 1. No digit for the last `None`, or a fully fixed template, passes the Luhn Algorithm.
+    /// 2. First digit is fixed, but not 1 or 2.
+    /// 3. A fixed digit is greater than 9.
+    #[cfg(feature = "rand")]
+    pub fn from_template_with_rng<R: rand::Rng + ?Sized>(
+        template: &[Option<u8>; ID_SIZE],
+        rng: &mut R,
+    ) -> Result<Self> {
+        if let Some(position) = template
+            .iter()
+            .position(|digit| digit.is_some_and(|digit| digit > 9))
+        {
+            return Err(ParseError::NonDigitCharacter { position });
+        }
+        if let Some(got) = template[0].filter(|&prefix| IdType::from_prefix(prefix).is_none()) {
+            return Err(ParseError::InvalidPrefix { got });
         }
+
+        let mut digits = template.map(|digit| digit.unwrap_or_else(|| rng.gen_range(0..10)));
+        if template[0].is_none() {
+            digits[0] = if rng.gen() {
+                CITIZEN_PREFIX
+            } else {
+                RESIDENT_PREFIX
+            };
+        }
+
+        if let Some(position) = template.iter().rposition(Option::is_none) {
+            let candidates = if position == 0 {
+                CITIZEN_PREFIX..=RESIDENT_PREFIX
+            } else {
+                0..=9
+            };
+
+            for digit in candidates {
+                digits[position] = digit;
+
+                if validate_const(&digits) {
+                    break;
+                }
+            }
+        }
+
+        Self::try_from(digits)
     }
-}
 
-impl core::str::FromStr for Id {
-    type Err = ParseError;
+    /// An endless iterator of new random IDs, e.g. `Id::random_iter(IdType::Citizen).take(50)`.
+    #[cfg(feature = "std")]
+    pub fn random_iter(id_type: IdType) -> impl Iterator<Item = Self> {
+        core::iter::repeat_with(move || Self::new(id_type))
+    }
 
-    /// # Errors
+    /// Create `count` different random IDs, see [`Id::generate_unique_with_rng`].
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn generate_unique(id_type: IdType, count: usize) -> Vec<Self> {
+        Self::generate_unique_with_rng(id_type, count, &mut rand::thread_rng())
+    }
+
+    /// Create `count` different random IDs using `rng`, sorted in ascending order.
     ///
-    /// 1. String can't be parsed as an integer.
-    /// 2. Not using the Luhn Algorithm.
-    /// 3. More or less then 10 digits.
-    /// 4. First digit is not 1 or 2.
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.parse::<u32>().map_or(Err(ParseError::InvalidId), |num| {
-            Self::try_from(num).map_or(Err(ParseError::InvalidId), Ok)
-        })
+    /// Takes exactly `count` random draws, no matter how close `count` is to the number of possible IDs.
+    ///
+    /// There are only [`valid_count`] IDs of each type, so a bigger `count` returns all of them.
+    #[cfg(feature = "rand")]
+    #[must_use]
+    pub fn generate_unique_with_rng<R: rand::Rng + ?Sized>(
+        id_type: IdType,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<Self> {
+        let count = u32::try_from(count).map_or(IDS_PER_TYPE, |count| count.min(IDS_PER_TYPE));
+
+        // Robert Floyd's sampling algorithm, every draw adds one new number.
+        let mut picked = BTreeSet::new();
+        for upper in IDS_PER_TYPE - count..IDS_PER_TYPE {
+            let n = rng.gen_range(0..=upper);
+
+            if !picked.insert(n) {
+                picked.insert(upper);
+            }
+        }
+
+        picked
+            .into_iter()
+            .map(|n| Self::from_payload_number(id_type, n))
+            .collect()
     }
-}
 
-impl core::fmt::Display for Id {
-    #[expect(clippy::unwrap_used, reason = "Should never fail")]
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.digits
-                .clone()
-                .into_iter()
-                .enumerate()
-                .fold(0, |acc: u32, (i, digit)| {
-                    const ITER_INDEXES: usize = ID_SIZE - 1;
+    /// The ID with the 8 digits between the prefix and the check digit set to `n`, which must be below
+    /// [`IDS_PER_TYPE`].
+    #[cfg(any(test, feature = "rand", feature = "proptest", feature = "quickcheck"))]
+    fn from_payload_number(id_type: IdType, mut n: u32) -> Self {
+        let mut digits = [0; ID_SIZE];
 
-                    acc + (u32::from(digit)
-                        * (10_u32.pow(u32::try_from(ITER_INDEXES - i).unwrap())))
-                })
-        )
+        digits[0] = id_type.prefix();
+        for digit in digits[1..ID_SIZE - 1].iter_mut().rev() {
+            *digit = (n % 10) as u8;
+            n /= 10;
+        }
+
+        Self::complete(digits)
     }
-}
 
-impl Clone for Id {
-    fn clone(&self) -> Self {
+    /// Replace the last digit with the Luhn check digit, the other digits must already be valid.
+    fn complete(mut digits: [u8; ID_SIZE]) -> Self {
+        digits[ID_SIZE - 1] = luhn_check_digit(&digits[..ID_SIZE - 1]);
+
         Self {
-            digits: self.digits.clone(),
+            digits: Vec::from(digits),
         }
     }
-}
-
-#[expect(clippy::allow_attributes_without_reason)]
-#[expect(clippy::unwrap_used)]
-#[cfg(test)]
-mod tests {
-    use core::str::FromStr;
 
-    use super::*;
+    fn validate(digits: &[u8]) -> Result<()> {
+        let Ok(digits) = <&[u8; ID_SIZE]>::try_from(digits) else {
+            return Err(ParseError::WrongLength { got: digits.len() });
+        };
 
-    #[test]
-    fn static_tests() {
-        let id = Id::try_from(1_581_872_353).unwrap();
-        assert_eq!(id.get_type(), IdType::Citizen);
+        Self::validate_array(digits)
     }
 
-    #[test]
-    fn random_generated_tests() {
-        for _ in 0..10_000_usize {
-            let cit_id = Id::new(&IdType::Citizen);
-            assert_eq!(cit_id, Id::try_from(cit_id.digits.clone()).unwrap());
-            assert_eq!(cit_id, Id::from_str(&cit_id.to_string()).unwrap());
-            assert_eq!(
-                cit_id.to_string(),
-                cit_id
-                    .digits
-                    .clone()
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<String>()
-            );
+    /// Same as [`Id::validate`], without the length check.
+    fn validate_array(digits: &[u8; ID_SIZE]) -> Result<()> {
+        if let Some(position) = digits.iter().position(|&digit| digit > 9) {
+            return Err(ParseError::NonDigitCharacter { position });
+        }
 
-            let res_id = Id::new(&IdType::Resident);
-            assert_eq!(res_id, Id::try_from(res_id.digits.clone()).unwrap());
-            assert_eq!(res_id, Id::from_str(&res_id.to_string()).unwrap());
-            assert_eq!(
-                res_id.to_string(),
-                res_id
-                    .digits
-                    .clone()
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<String>()
-            );
+        let [prefix, .., check] = *digits;
+
+        if IdType::from_prefix(prefix).is_none() {
+            return Err(ParseError::InvalidPrefix { got: prefix });
         }
+
+        if luhn_check_digit(&digits[..ID_SIZE - 1]) == check {
+            Ok(())
+        } else {
+            Err(ParseError::FailedLuhn)
+        }
+    }
+
+    #[expect(clippy::missing_panics_doc, reason = "Never panics")]
+    #[must_use]
+    pub fn get_type(&self) -> IdType {
+        #[expect(clippy::unwrap_used, reason = "Digits are always valid")]
+        self.try_get_type().unwrap()
+    }
+
+    /// Same as [`Id::get_type`], but checks the prefix instead of relying on the `Id` being valid.
+    ///
+    /// # Errors
+    ///
+    /// 1. First digit is not 1 or 2.
+    /// 2. There are no digits.
+    pub fn try_get_type(&self) -> Result<IdType> {
+        let &got = self
+            .digits
+            .first()
+            .ok_or(ParseError::WrongLength { got: 0 })?;
+
+        IdType::from_prefix(got).ok_or(ParseError::InvalidPrefix { got })
+    }
+
+    /// Check if this is a citizen ID, starting with 1.
+    #[must_use]
+    pub fn is_citizen(&self) -> bool {
+        self.digits.first() == Some(&CITIZEN_PREFIX)
+    }
+
+    /// Check if this is a resident ID, starting with 2.
+    #[must_use]
+    pub fn is_resident(&self) -> bool {
+        self.digits.first() == Some(&RESIDENT_PREFIX)
+    }
+
+    /// Derived facts about this ID, computed from its digits only.
+    #[must_use]
+    pub fn metadata(&self) -> Metadata {
+        Metadata {
+            id_type: self.get_type(),
+            value: self.to_u64(),
+        }
+    }
+
+    /// The first 9 digits, which the check digit is computed from.
+    #[must_use]
+    pub fn payload(&self) -> &[u8] {
+        &self.digits[..ID_SIZE - 1]
+    }
+
+    /// The last digit, computed from the others by [`compute_check_digit`].
+    #[must_use]
+    pub fn check_digit(&self) -> u8 {
+        self.digits[ID_SIZE - 1]
+    }
+
+    /// Borrow the digits, each one is a number from 0 to 9 (not an ASCII character).
+    #[must_use]
+    pub fn digits(&self) -> &[u8] {
+        &self.digits
+    }
+
+    /// Take the digits, each one is a number from 0 to 9 (not an ASCII character).
+    ///
+    /// They are not wiped when dropped, even with the `zeroize` feature.
+    #[must_use]
+    pub fn into_digits(mut self) -> Vec<u8> {
+        // NOTE: Taken instead of moved, since `Id` implements `Drop` with the `zeroize` feature.
+        core::mem::take(&mut self.digits)
+    }
+
+    /// Overwrite the digits with zeros and clear them, leaving an invalid `Id`, so it's only called when dropping.
+    #[cfg(feature = "zeroize")]
+    fn wipe(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.digits);
+    }
+
+    /// Copy the digits into a fixed-size array.
+    #[must_use]
+    pub fn as_array(&self) -> [u8; ID_SIZE] {
+        let mut digits = [0; ID_SIZE];
+        digits.copy_from_slice(&self.digits);
+
+        digits
+    }
+
+    /// The ID of `id_type` with the 8 `payload` digits after the prefix, and the computed check digit.
+    ///
+    /// # Panics
+    ///
+    /// If a payload digit is greater than 9.
+    #[must_use]
+    pub fn from_payload(id_type: IdType, payload: &[u8; ID_SIZE - 2]) -> Self {
+        assert!(
+            payload.iter().all(|&digit| digit <= 9),
+            "payload digits must be from 0 to 9"
+        );
+
+        let mut digits = [0; ID_SIZE];
+        digits[0] = id_type.prefix();
+        digits[1..ID_SIZE - 1].copy_from_slice(payload);
+
+        Self::complete(digits)
+    }
+
+    /// Collect and validate digits produced by an iterator, each one a number from 0 to 9.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`TryFrom<Vec<u8>>`](#impl-TryFrom<Vec<u8>>-for-Id).
+    pub fn from_digits_iter<I: IntoIterator<Item = u8>>(digits: I) -> Result<Self> {
+        Self::try_from(digits.into_iter().collect::<Vec<_>>())
+    }
+
+    /// Check if `n` is the same ID, `false` for any other number.
+    #[must_use]
+    pub fn equals_number(&self, n: u64) -> bool {
+        *self == n
+    }
+
+    /// Check if `s` is the same ID, parsed like [`FromStr`](core::str::FromStr), `false` for malformed strings.
+    #[must_use]
+    pub fn equals_str(&self, s: &str) -> bool {
+        *self == *s
+    }
+
+    /// The digits as ASCII characters, e.g. `*b"1564437091"`.
+    #[must_use]
+    pub fn to_ascii_bytes(&self) -> [u8; ID_SIZE] {
+        self.as_array().map(|digit| b'0' + digit)
+    }
+
+    /// Parse 10 ASCII digit characters, e.g. `b"1564437091"`.
+    ///
+    /// # Errors
+    ///
+    /// 1. A byte is not an ASCII digit.
+    /// 2. Not using the Luhn Algorithm.
+    /// 3. First digit is not 1 or 2.
+    pub fn from_ascii_bytes(bytes: &[u8; ID_SIZE]) -> Result<Self> {
+        if let Some(position) = bytes.iter().position(|byte| !byte.is_ascii_digit()) {
+            return Err(ParseError::NonDigitCharacter { position });
+        }
+
+        Self::try_from(bytes.map(|byte| byte - b'0'))
+    }
+
+    /// Parse a string of exactly 10 ASCII digits, without skipping anything, so the digits are the same as the input
+    /// characters in order.
+    ///
+    /// # Errors
+    ///
+    /// 1. String contains a character that is not an ASCII digit.
+    /// 2. Not using the Luhn Algorithm.
+    /// 3. More or less then 10 digits.
+    /// 4. First digit is not 1 or 2.
+    pub fn from_exact_digits(s: &str) -> Result<Self> {
+        if let Some(position) = s.chars().position(|c| !c.is_ascii_digit()) {
+            return Err(ParseError::NonDigitCharacter { position });
+        }
+
+        // All characters are ASCII, so each one is a single byte.
+        let Ok(bytes) = <&[u8; ID_SIZE]>::try_from(s.as_bytes()) else {
+            return Err(ParseError::WrongLength { got: s.len() });
+        };
+
+        Self::from_ascii_bytes(bytes)
+    }
+
+    /// The ID as a number.
+    #[must_use]
+    pub fn to_u64(&self) -> u64 {
+        self.digits
+            .iter()
+            .fold(0, |acc, &digit| acc * 10 + u64::from(digit))
+    }
+
+    /// The ID as a number, it always fits since the first digit is 1 or 2.
+    #[must_use]
+    pub fn to_u32(&self) -> u32 {
+        self.digits
+            .iter()
+            .fold(0, |acc, &digit| acc * 10 + u32::from(digit))
+    }
+
+    /// The digits written with Arabic-Indic numerals (`٠`-`٩`), e.g. `"١٥٦٤٤٣٧٠٩١"`.
+    #[must_use]
+    pub fn to_arabic_string(&self) -> String {
+        self.digits
+            .iter()
+            .filter_map(|&digit| char::from_u32(ARABIC_INDIC_ZERO as u32 + u32::from(digit)))
+            .collect()
+    }
+
+    /// The ID for logs, showing only the prefix and the last two digits, e.g. `"1*******91"`.
+    #[must_use]
+    pub fn masked(&self) -> String {
+        self.masked_with(1, 2, '*')
+    }
+
+    /// Same as [`Id::masked`], but showing the first `visible_prefix` and the last `visible_suffix` digits, and
+    /// replacing the others with `mask`.
+    #[must_use]
+    pub fn masked_with(&self, visible_prefix: usize, visible_suffix: usize, mask: char) -> String {
+        let suffix_start = ID_SIZE.saturating_sub(visible_suffix);
+
+        self.digits
+            .iter()
+            .enumerate()
+            .map(|(i, &digit)| {
+                if i < visible_prefix || i >= suffix_start {
+                    char::from(b'0' + digit)
+                } else {
+                    mask
+                }
+            })
+            .collect()
+    }
+
+    /// The digits grouped for readability as the prefix then three groups of three, e.g. `"1-564-437-091"` for `'-'`.
+    #[expect(clippy::missing_panics_doc, reason = "Never panics")]
+    #[must_use]
+    pub fn format_grouped(&self, separator: char) -> String {
+        let mut s = String::with_capacity(ID_SIZE + GROUP_STARTS.len() * separator.len_utf8());
+
+        #[expect(clippy::unwrap_used, reason = "Writing to a String never fails")]
+        self.write_grouped(&mut s, separator).unwrap();
+
+        s
+    }
+
+    fn write_grouped(&self, w: &mut impl Write, separator: char) -> core::fmt::Result {
+        for (i, digit) in self.digits.iter().enumerate() {
+            if GROUP_STARTS.contains(&i) {
+                w.write_char(separator)?;
+            }
+            w.write_char(char::from(b'0' + digit))?;
+        }
+
+        Ok(())
+    }
+
+    /// Parse a string after skipping any ASCII whitespace and any of the `separators` characters.
+    ///
+    /// # Errors
+    ///
+    /// 1. String contains a character that is not a digit, whitespace or one of the `separators`.
+    /// 2. Not using the Luhn Algorithm.
+    /// 3. More or less then 10 digits, not counting skipped characters.
+    /// 4. First digit is not 1 or 2.
+    pub fn parse_with_separators(s: &str, separators: &[char]) -> Result<Self> {
+        Self::try_from(Self::parse_digits(s, |c| {
+            c.is_ascii_whitespace() || separators.contains(&c)
+        })?)
+    }
+
+    /// Convert every character to a digit, ignoring the ones matched by `skip`, without allocating.
+    fn parse_digits(s: &str, skip: impl Fn(char) -> bool) -> Result<[u8; ID_SIZE]> {
+        let mut digits = [0; ID_SIZE];
+        let mut count = 0;
+
+        for (position, c) in s.chars().enumerate().filter(|&(_, c)| !skip(c)) {
+            let digit = digit_value(c).ok_or(ParseError::NonDigitCharacter { position })?;
+
+            if let Some(slot) = digits.get_mut(count) {
+                *slot = digit;
+            }
+            count += 1;
+        }
+
+        if count == ID_SIZE {
+            Ok(digits)
+        } else {
+            Err(ParseError::WrongLength { got: count })
+        }
+    }
+}
+
+/// Builds an [`Id`] from digits pushed one at a time, either all 10 of them or the first 9 to compute the check digit.
+#[derive(Clone, Default, Debug)]
+pub struct IdBuilder {
+    digits: [u8; ID_SIZE],
+    len: usize,
+}
+
+impl IdBuilder {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            digits: [0; ID_SIZE],
+            len: 0,
+        }
+    }
+
+    /// Add the next digit, extra digits are only counted to be reported by [`IdBuilder::finish`].
+    pub fn push_digit(&mut self, digit: u8) -> &mut Self {
+        if let Some(slot) = self.digits.get_mut(self.len) {
+            *slot = digit;
+        }
+        self.len += 1;
+
+        self
+    }
+
+    /// Validate the 10 pushed digits, or compute the check digit after 9 of them.
+    ///
+    /// # Errors
+    ///
+    /// 1. Not using the Luhn Algorithm, when 10 digits were pushed.
+    /// 2. Other than 9 or 10 digits were pushed.
+    /// 3. First digit is not 1 or 2.
+    /// 4. A digit is greater than 9.
+    pub fn finish(&self) -> Result<Id> {
+        match self.len {
+            ID_SIZE => Id::try_from(self.digits),
+            len if len == ID_SIZE - 1 => {
+                let payload = &self.digits[..len];
+
+                if let Some(position) = payload.iter().position(|&digit| digit > 9) {
+                    return Err(ParseError::NonDigitCharacter { position });
+                }
+                if IdType::from_prefix(payload[0]).is_none() {
+                    return Err(ParseError::InvalidPrefix { got: payload[0] });
+                }
+
+                Ok(Id::complete(self.digits))
+            }
+            got => Err(ParseError::WrongBuilderLength { got }),
+        }
+    }
+}
+
+// NOTE: Only zeroized on drop, since a zeroized `Id` would no longer be valid.
+#[cfg(feature = "zeroize")]
+impl Drop for Id {
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Id {}
+
+impl TryFrom<u32> for Id {
+    type Error = ParseError;
+
+    /// # Errors
+    ///
+    /// 1. Not using the Luhn Algorithm.
+    /// 2. More or less then 10 digits.
+    /// 3. First digit is not 1 or 2.
+    fn try_from(id: u32) -> Result<Self, Self::Error> {
+        Self::try_from(u64::from(id))
+    }
+}
+
+/// Integer literals need a suffix, e.g. `Id::try_from(1_564_437_091_u64)`, since there is also [`TryFrom<u32>`].
+impl TryFrom<u64> for Id {
+    type Error = ParseError;
+
+    /// # Errors
+    ///
+    /// 1. Not using the Luhn Algorithm.
+    /// 2. More or less then 10 digits.
+    /// 3. First digit is not 1 or 2.
+    ///
+    /// `0` is treated as the single digit `0`, so it fails the length check like any other short number.
+    fn try_from(mut id: u64) -> Result<Self, Self::Error> {
+        // Count the digits first, so a wrong length fails before building anything.
+        let len = id.checked_ilog10().map_or(1, |log| log as usize + 1);
+        if len != ID_SIZE {
+            return Err(ParseError::WrongLength { got: len });
+        }
+
+        let mut digits = vec![0; ID_SIZE];
+        for digit in digits.iter_mut().rev() {
+            *digit = (id % 10) as u8;
+            id /= 10;
+        }
+
+        Self::try_from(digits)
+    }
+}
+
+impl TryFrom<Vec<u8>> for Id {
+    type Error = ParseError;
+
+    /// # Errors
+    ///
+    /// 1. Not using the Luhn Algorithm.
+    /// 2. More or less then 10 digits.
+    /// 3. First digit is not 1 or 2.
+    /// 4. A digit is greater than 9.
+    fn try_from(digits: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::validate(&digits)?;
+
+        Ok(Self { digits })
+    }
+}
+
+impl TryFrom<[u8; ID_SIZE]> for Id {
+    type Error = ParseError;
+
+    /// # Errors
+    ///
+    /// 1. A digit is greater than 9.
+    /// 2. Not using the Luhn Algorithm.
+    /// 3. First digit is not 1 or 2.
+    fn try_from(digits: [u8; ID_SIZE]) -> Result<Self, Self::Error> {
+        Self::validate_array(&digits)?;
+
+        Ok(Self {
+            digits: Vec::from(digits),
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for Id {
+    type Error = ParseError;
+
+    /// Only copies the digits after they are validated.
+    ///
+    /// # Errors
+    ///
+    /// 1. Not using the Luhn Algorithm.
+    /// 2. More or less then 10 digits.
+    /// 3. First digit is not 1 or 2.
+    /// 4. A digit is greater than 9.
+    fn try_from(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::validate(digits)?;
+
+        Ok(Self {
+            digits: digits.to_vec(),
+        })
+    }
+}
+
+impl core::str::FromStr for Id {
+    type Err = ParseError;
+
+    /// Digits can be ASCII (`0`-`9`), Arabic-Indic (`٠`-`٩`) or Extended Arabic-Indic (`۰`-`۹`), even mixed.
+    ///
+    /// ASCII whitespace and `-` are skipped, so grouped input like `"1-564-437-091"` or `"1 564 437 091"` is accepted.
+    /// Signs are rejected, both `+` and a `-` before the first digit. Any other character is rejected, including
+    /// non-ASCII whitespace, `_` and `.`.
+    ///
+    /// # Errors
+    ///
+    /// 1. String contains a character that is not a digit, whitespace or `-`, or starts with a sign.
+    /// 2. Not using the Luhn Algorithm.
+    /// 3. More or less then 10 digits, not counting skipped characters.
+    /// 4. First digit is not 1 or 2.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(parse_default(s)?)
+    }
+}
+
+impl TryFrom<&str> for Id {
+    type Error = ParseError;
+
+    /// Same as [`FromStr`](core::str::FromStr).
+    ///
+    /// # Errors
+    ///
+    /// 1. String contains a character that is not a digit, whitespace or `-`.
+    /// 2. Not using the Luhn Algorithm.
+    /// 3. More or less then 10 digits, not counting skipped characters.
+    /// 4. First digit is not 1 or 2.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl TryFrom<String> for Id {
+    type Error = ParseError;
+
+    /// Same as [`FromStr`](core::str::FromStr).
+    ///
+    /// # Errors
+    ///
+    /// 1. String contains a character that is not a digit, whitespace or `-`.
+    /// 2. Not using the Luhn Algorithm.
+    /// 3. More or less then 10 digits, not counting skipped characters.
+    /// 4. First digit is not 1 or 2.
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl core::fmt::Display for Id {
+    /// Bare 10 digits, or grouped for readability with the alternate flag (`{:#}`), e.g. `1 564 437 091`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return self.write_grouped(f, ' ');
+        }
+
+        // NOTE: Written at once, since formatting each digit on its own is a few times slower.
+        let bytes = self.to_ascii_bytes();
+        let Ok(digits) = core::str::from_utf8(&bytes) else {
+            return Err(core::fmt::Error);
+        };
+
+        f.write_str(digits)
+    }
+}
+
+impl From<Id> for String {
+    fn from(id: Id) -> Self {
+        id.to_string()
+    }
+}
+
+impl From<&Id> for u64 {
+    fn from(id: &Id) -> Self {
+        id.to_u64()
+    }
+}
+
+impl PartialEq<u32> for Id {
+    fn eq(&self, other: &u32) -> bool {
+        self.to_u32() == *other
+    }
+}
+
+impl PartialEq<Id> for u32 {
+    fn eq(&self, other: &Id) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<u64> for Id {
+    fn eq(&self, other: &u64) -> bool {
+        self.to_u64() == *other
+    }
+}
+
+impl PartialEq<Id> for u64 {
+    fn eq(&self, other: &Id) -> bool {
+        other == self
+    }
+}
+
+/// Parsed like [`FromStr`](core::str::FromStr), so a string that is not an ID is never equal.
+impl PartialEq<str> for Id {
+    fn eq(&self, other: &str) -> bool {
+        parse_default(other).is_ok_and(|digits| self.digits == digits)
+    }
+}
+
+impl PartialEq<Id> for str {
+    fn eq(&self, other: &Id) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<&str> for Id {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<Id> for &str {
+    fn eq(&self, other: &Id) -> bool {
+        other == *self
+    }
+}
+
+/// Each byte is a number from 0 to 9, not an ASCII character.
+impl AsRef<[u8]> for Id {
+    fn as_ref(&self) -> &[u8] {
+        &self.digits
+    }
+}
+
+impl<'id> IntoIterator for &'id Id {
+    type Item = &'id u8;
+    type IntoIter = core::slice::Iter<'id, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.digits.iter()
+    }
+}
+
+/// Same as [`Id::into_digits`], the digits are not wiped when dropped.
+impl IntoIterator for Id {
+    type Item = u8;
+    type IntoIter = vec::IntoIter<u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_digits().into_iter()
+    }
+}
+
+/// Read-only access to the digits as a slice, each byte is a number from 0 to 9, not an ASCII character.
+impl core::ops::Deref for Id {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.digits
+    }
+}
+
+#[expect(clippy::allow_attributes_without_reason)]
+#[expect(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn static_tests() {
+        // NOTE: The suffix is needed since `Id` is also `TryFrom<u64>`.
+        let id = Id::try_from(1_581_872_353_u32).unwrap();
+        assert_eq!(id.get_type(), IdType::Citizen);
+    }
+
+    #[test]
+    fn parse_error_display() {
+        let err: Box<dyn core::error::Error> =
+            Box::new(Id::try_from(1_234_567_890_u32).unwrap_err());
+
+        assert_eq!(err.to_string(), "invalid Saudi ID: failed Luhn validation");
+    }
+
+    #[test]
+    fn parse_error_variants() {
+        assert_eq!(
+            Id::try_from(158_187_235_u32),
+            Err(ParseError::WrongLength { got: 9 })
+        );
+        assert_eq!(
+            Id::try_from(3_581_872_353_u32),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+        assert_eq!(Id::try_from(1_581_872_354_u32), Err(ParseError::FailedLuhn));
+        assert_eq!(
+            Id::from_str("15818a2353"),
+            Err(ParseError::NonDigitCharacter { position: 5 })
+        );
+    }
+
+    #[test]
+    fn parse_error_variants_are_consistent() {
+        assert_eq!(
+            Id::try_from(vec![1, 5, 8, 1, 8, 7, 2, 3, 5]),
+            Err(ParseError::WrongLength { got: 9 })
+        );
+        assert_eq!(
+            Id::from_str("158187235"),
+            Err(ParseError::WrongLength { got: 9 })
+        );
+
+        assert_eq!(
+            Id::try_from(vec![3, 5, 8, 1, 8, 7, 2, 3, 5, 3]),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+        assert_eq!(
+            Id::from_str("3581872353"),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+
+        assert_eq!(
+            Id::try_from(vec![1, 5, 8, 1, 8, 7, 2, 3, 5, 4]),
+            Err(ParseError::FailedLuhn)
+        );
+        assert_eq!(Id::from_str("1581872354"), Err(ParseError::FailedLuhn));
+    }
+
+    #[test]
+    fn luhn_valid_unknown_prefix_is_rejected() {
+        let digits = vec![3, 5, 8, 1, 8, 7, 2, 3, 5, 9];
+        assert_eq!(luhn_check_digit(&digits[..9]), 9);
+
+        assert_eq!(
+            Id::try_from(3_581_872_359_u32),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+        assert_eq!(
+            Id::from_str("3581872359"),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+        assert_eq!(
+            Id::try_from(digits),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+    }
+
+    #[test]
+    fn parse_from_u64() {
+        assert_eq!(
+            Id::try_from(1_581_872_353_u64).unwrap(),
+            Id::try_from(1_581_872_353_u32).unwrap()
+        );
+        assert_eq!(
+            Id::try_from(15_818_723_530_u64),
+            Err(ParseError::WrongLength { got: 11 })
+        );
+        assert_eq!(
+            Id::try_from(u64::MAX),
+            Err(ParseError::WrongLength { got: 20 })
+        );
+    }
+
+    #[test]
+    fn parse_from_str_ref() {
+        assert_eq!(Id::try_from("1564437091"), Id::from_str("1564437091"));
+        assert_eq!(
+            Id::try_from("156443709"),
+            Err(ParseError::WrongLength { got: 9 })
+        );
+    }
+
+    #[test]
+    fn parse_short_integers() {
+        assert_eq!(Id::try_from(0_u32), Err(ParseError::WrongLength { got: 1 }));
+        assert_eq!(Id::try_from(1_u32), Err(ParseError::WrongLength { got: 1 }));
+        assert_eq!(
+            Id::try_from(158_187_235_u32),
+            Err(ParseError::WrongLength { got: 9 })
+        );
+    }
+
+    #[test]
+    fn validate_without_allocation() {
+        assert!(is_valid(&[1, 5, 8, 1, 8, 7, 2, 3, 5, 3]));
+        assert!(!is_valid(&[1, 5, 8, 1, 8, 7, 2, 3, 5, 4]));
+        assert!(!is_valid(&[3, 5, 8, 1, 8, 7, 2, 3, 5, 9]));
+        assert!(!is_valid(&[1, 5, 8, 1, 8, 7, 2, 3, 5]));
+        assert!(!is_valid(&[]));
+
+        assert!(is_valid_str("1581872353"));
+        assert!(is_valid_str("١٥٨١٨٧٢٣٥٣"));
+        assert!(!is_valid_str("1581872354"));
+        assert!(!is_valid_str("15818723530"));
+        assert!(is_valid_str("1581-872353"));
+        assert!(!is_valid_str("1581.872353"));
+    }
+
+    #[test]
+    fn parse_with_separators() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(Id::from_str("1-564-437-091").unwrap(), id);
+        assert_eq!(Id::from_str("1-564-437091").unwrap(), id);
+        assert_eq!(Id::from_str(" 1564 437 091\n").unwrap(), id);
+        assert_eq!(Id::from_str("1 5644 37091").unwrap(), id);
+        assert_eq!(
+            Id::parse_with_separators("1.564.437.091", &['.']).unwrap(),
+            id
+        );
+        assert_eq!(
+            Id::from_str("1.564.437.091"),
+            Err(ParseError::NonDigitCharacter { position: 1 })
+        );
+        assert_eq!(
+            Id::from_str("1-564-43a-091"),
+            Err(ParseError::NonDigitCharacter { position: 8 })
+        );
+        assert_eq!(
+            Id::from_str("1-564-437-09"),
+            Err(ParseError::WrongLength { got: 9 })
+        );
+    }
+
+    #[test]
+    fn parse_arabic_indic_digits() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(Id::from_str("١٥٦٤٤٣٧٠٩١").unwrap(), id);
+        assert_eq!(Id::from_str("۱۵۶۴۴۳۷۰۹۱").unwrap(), id);
+        assert_eq!(Id::from_str("١-٥٦٤-٤٣٧-٠٩١").unwrap(), id);
+        assert_eq!(Id::from_str("15٦٤٤٣۷091").unwrap(), id);
+        assert_eq!(
+            Id::from_str("15٦٤٤٣۷09١x"),
+            Err(ParseError::NonDigitCharacter { position: 10 })
+        );
+    }
+
+    #[test]
+    fn grouped_display() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(format!("{id:#}"), "1 564 437 091");
+        assert_eq!(format!("{id}"), "1564437091");
+        assert_eq!(Id::from_str(&format!("{id:#}")).unwrap(), id);
+    }
+
+    #[test]
+    fn hash_set_dedup() {
+        let id = Id::from_str("1564437091").unwrap();
+        let ids: std::collections::HashSet<Id> = [
+            id.clone(),
+            id.clone(),
+            Id::from_str("1581872353").unwrap(),
+            id,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(ids.len(), 2);
+
+        let types: std::collections::HashSet<IdType> =
+            [IdType::Citizen, IdType::Resident, IdType::Citizen]
+                .into_iter()
+                .collect();
+        assert_eq!(types.len(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sort_by_numeric_value() {
+        let mut ids: Vec<Id> = (0..100_usize)
+            .flat_map(|_| [Id::new(IdType::Citizen), Id::new(IdType::Resident)])
+            .collect();
+        ids.sort();
+
+        let numbers: Vec<u64> = ids
+            .iter()
+            .map(|id| id.to_string().parse().unwrap())
+            .collect();
+        assert!(numbers.is_sorted());
+    }
+
+    #[test]
+    fn type_of_hand_built_id() {
+        assert_eq!(
+            Id::from_str("2000000006").unwrap().try_get_type(),
+            Ok(IdType::Resident)
+        );
+        assert_eq!(
+            Id {
+                digits: vec![3; 10]
+            }
+            .try_get_type(),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+        assert_eq!(
+            Id { digits: vec![] }.try_get_type(),
+            Err(ParseError::WrongLength { got: 0 })
+        );
+    }
+
+    #[test]
+    fn luhn_check_digit_is_unique() {
+        for payload in [
+            [1, 5, 6, 4, 4, 3, 7, 0, 9],
+            [2, 0, 0, 0, 0, 0, 0, 0, 0],
+            [1, 9, 9, 9, 9, 9, 9, 9, 9],
+        ] {
+            let check = luhn_check_digit(&payload);
+            let mut digits = payload.to_vec();
+            digits.push(check);
+
+            assert!(is_valid(&digits));
+            assert!((0..10).filter(|&other| other != check).all(|other| {
+                digits[9] = other;
+                !is_valid(&digits)
+            }));
+        }
+    }
+
+    #[test]
+    fn parse_from_string_digit_by_digit() {
+        assert_eq!(
+            Id::try_from(String::from("1564437091")),
+            Id::try_from("1564437091")
+        );
+        assert_eq!(
+            Id::try_from("0158187235"),
+            Err(ParseError::InvalidPrefix { got: 0 })
+        );
+        assert_eq!(
+            Id::try_from("15644370910"),
+            Err(ParseError::WrongLength { got: 11 })
+        );
+        assert_eq!(
+            Id::try_from("1564437091 "),
+            Id::try_from(String::from("1564 437091"))
+        );
+        assert_eq!(
+            Id::try_from("1564437091_"),
+            Err(ParseError::NonDigitCharacter { position: 10 })
+        );
+        // Fullwidth digits are not supported.
+        assert_eq!(
+            Id::try_from("１564437091"),
+            Err(ParseError::NonDigitCharacter { position: 0 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn random_generated_tests() {
+        for _ in 0..10_000_usize {
+            let cit_id = Id::new(IdType::Citizen);
+            assert_eq!(cit_id, Id::try_from(cit_id.digits().to_vec()).unwrap());
+            assert_eq!(cit_id, Id::from_str(&cit_id.to_string()).unwrap());
+            assert_eq!(
+                cit_id.to_string(),
+                cit_id
+                    .digits()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<String>()
+            );
+
+            let res_id = Id::new(IdType::Resident);
+            assert_eq!(res_id, Id::try_from(res_id.clone().into_digits()).unwrap());
+            assert_eq!(res_id, Id::from_str(&res_id.to_string()).unwrap());
+            assert_eq!(
+                res_id.to_string(),
+                res_id
+                    .digits()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<String>()
+            );
+        }
+    }
+
+    #[test]
+    fn convert_to_integers() {
+        let id = Id::from_str("2000000006").unwrap();
+
+        assert_eq!(id.to_u64(), 2_000_000_006);
+        assert_eq!(id.to_u32(), 2_000_000_006);
+        assert_eq!(Id::try_from(id.to_u64()).unwrap(), id);
+    }
+
+    #[test]
+    fn parse_u64_boundaries() {
+        assert_eq!(
+            Id::try_from(999_999_999_u64),
+            Err(ParseError::WrongLength { got: 9 })
+        );
+        assert_eq!(
+            Id::try_from(3_000_000_000_u64),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+        assert_eq!(
+            Id::try_from(10_000_000_000_u64),
+            Err(ParseError::WrongLength { got: 11 })
+        );
+        assert_eq!(
+            Id::try_from(1_000_000_008_u64).unwrap().to_u64(),
+            1_000_000_008
+        );
+    }
+
+    #[test]
+    fn parse_from_array() {
+        assert_eq!(
+            Id::try_from([1, 5, 6, 4, 4, 3, 7, 0, 9, 1]).unwrap(),
+            Id::from_str("1564437091").unwrap()
+        );
+        assert_eq!(
+            Id::try_from([0; ID_SIZE]),
+            Err(ParseError::InvalidPrefix { got: 0 })
+        );
+        assert_eq!(
+            Id::try_from([1, 5, 6, 4, 4, 3, 7, 0, 9, 2]),
+            Err(ParseError::FailedLuhn)
+        );
+    }
+
+    #[test]
+    fn arabic_indic_string() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(id.to_arabic_string(), "١٥٦٤٤٣٧٠٩١");
+        assert_eq!(Id::from_str(&id.to_arabic_string()).unwrap(), id);
+    }
+
+    #[test]
+    fn format_with_separator() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(id.format_grouped('-'), "1-564-437-091");
+        assert_eq!(id.format_grouped('\u{a0}'), "1\u{a0}564\u{a0}437\u{a0}091");
+        assert_eq!(id.format_grouped(' '), format!("{id:#}"));
+    }
+
+    #[test]
+    fn copy_into_array() {
+        let digits = [1, 5, 6, 4, 4, 3, 7, 0, 9, 1];
+
+        assert_eq!(Id::try_from(digits).unwrap().as_array(), digits);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_length() {
+        for _ in 0..1_000_usize {
+            assert_eq!(Id::new(IdType::Citizen).to_string().len(), ID_SIZE);
+            assert_eq!(Id::new(IdType::Resident).to_string().len(), ID_SIZE);
+        }
+    }
+
+    #[test]
+    fn hash_map_keys() {
+        let mut scans = std::collections::HashMap::new();
+        for scanned in ["1564437091", "1-564-437-091", "١٥٦٤٤٣٧٠٩١", "1581872353"] {
+            *scans
+                .entry(Id::from_str(scanned).unwrap())
+                .or_insert(0_usize) += 1;
+        }
+
+        assert_eq!(scans.len(), 2);
+        assert_eq!(scans[&Id::try_from(1_564_437_091_u64).unwrap()], 3);
+    }
+
+    #[test]
+    fn convert_into_string_and_u64() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(Id::try_from(u64::from(&id)).unwrap(), id);
+        assert_eq!(Id::try_from(String::from(id.clone())).unwrap(), id);
+    }
+
+    #[test]
+    fn binary_search_sorted_ids() {
+        let mut ids: Vec<Id> = [
+            2_000_000_006_u64,
+            1_581_872_353,
+            1_000_000_008,
+            1_564_437_091,
+        ]
+        .into_iter()
+        .map(|n| Id::try_from(n).unwrap())
+        .collect();
+        ids.sort();
+
+        assert_eq!(
+            ids.iter().map(Id::to_u64).collect::<Vec<_>>(),
+            [1_000_000_008, 1_564_437_091, 1_581_872_353, 2_000_000_006]
+        );
+        assert_eq!(
+            ids.binary_search(&Id::from_str("1581872353").unwrap()),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn array_with_out_of_range_digit() {
+        assert_eq!(
+            Id::try_from([1, 5, 6, 250, 4, 3, 7, 0, 9, 1]),
+            Err(ParseError::NonDigitCharacter { position: 3 })
+        );
+        assert_eq!(
+            Id::try_from([11, 5, 6, 4, 4, 3, 7, 0, 9, 1]),
+            Err(ParseError::NonDigitCharacter { position: 0 })
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn reproducible_with_seeded_rng() {
+        use rand::SeedableRng;
+
+        let mut first = rand::rngs::StdRng::seed_from_u64(42);
+        let mut second = rand::rngs::StdRng::seed_from_u64(42);
+
+        for id_type in [IdType::Citizen, IdType::Resident] {
+            for _ in 0..100_usize {
+                let id = Id::new_with_rng(id_type, &mut first);
+
+                assert_eq!(id, Id::new_with_rng(id_type, &mut second));
+                assert_eq!(id.get_type(), id_type);
+                assert_eq!(Id::try_from(id.digits()).unwrap(), id);
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample_from_distributions() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+
+        let ids: Vec<Id> = (&mut rng)
+            .sample_iter(rand::distributions::Standard)
+            .take(1000)
+            .collect();
+        let citizens = ids
+            .iter()
+            .filter(|id| id.get_type() == IdType::Citizen)
+            .count();
+        assert!((400..600).contains(&citizens));
+
+        let resident = rng.sample(IdType::Resident);
+        assert_eq!(resident.get_type(), IdType::Resident);
+        assert!(rng
+            .sample_iter(IdType::Citizen)
+            .take(100)
+            .all(|id| id.get_type() == IdType::Citizen));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn generate_unique_ids() {
+        for id_type in [IdType::Citizen, IdType::Resident] {
+            let ids = Id::generate_unique(id_type, 50_000);
+
+            assert_eq!(ids.len(), 50_000);
+            assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(ids.iter().all(|id| id.get_type() == id_type));
+            assert!(ids.iter().all(|id| is_valid(id.digits())));
+        }
+
+        assert!(Id::generate_unique(IdType::Citizen, 0).is_empty());
+    }
+
+    #[test]
+    fn vec_with_out_of_range_digit() {
+        assert_eq!(
+            Id::try_from(vec![1, 200, 6, 4, 4, 3, 7, 0, 9, 1]),
+            Err(ParseError::NonDigitCharacter { position: 1 })
+        );
+        assert_eq!(
+            Id::try_from([1, 5, 6, 4, 4, 3, 7, 0, 9, 10].as_slice()),
+            Err(ParseError::NonDigitCharacter { position: 9 })
+        );
+        assert!(!is_valid(&[1, 5, 6, 4, 4, 3, 7, 0, 9, 11]));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn reproducible_unique_with_seeded_rng() {
+        use rand::SeedableRng;
+
+        let mut first = rand::rngs::StdRng::seed_from_u64(7);
+        let mut second = rand::rngs::StdRng::seed_from_u64(7);
+
+        assert_eq!(
+            Id::generate_unique_with_rng(IdType::Resident, 100, &mut first),
+            Id::generate_unique_with_rng(IdType::Resident, 100, &mut second)
+        );
+    }
+
+    #[test]
+    fn find_all_in_text() {
+        assert_eq!(
+            find_all("IDs: 1564437091, (2000000006) and x1581872353y."),
+            vec![
+                Id::from_str("1564437091").unwrap(),
+                Id::from_str("2000000006").unwrap(),
+                Id::from_str("1581872353").unwrap(),
+            ]
+        );
+        assert_eq!(
+            find_all("Arabic: ١٥٦٤٤٣٧٠٩١"),
+            vec![Id::from_str("1564437091").unwrap()]
+        );
+
+        // Longer or shorter runs, invalid checksums and wrong prefixes.
+        assert!(find_all("15644370910 01564437091 156443709 1564437092 3581872359").is_empty());
+        assert!(find_all("").is_empty());
+    }
+
+    #[test]
+    fn citizen_or_resident() {
+        let citizen = Id::from_str("1564437091").unwrap();
+        let resident = Id::from_str("2000000006").unwrap();
+
+        assert!(citizen.is_citizen());
+        assert!(!citizen.is_resident());
+        assert!(resident.is_resident());
+        assert!(!resident.is_citizen());
+
+        let ids = [citizen, resident];
+        assert_eq!(ids.iter().filter(|id| id.is_resident()).count(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn random_iter_take() {
+        for id_type in [IdType::Citizen, IdType::Resident] {
+            let ids = Id::random_iter(id_type).take(5).collect::<Vec<_>>();
+
+            assert_eq!(ids.len(), 5);
+            assert!(ids.iter().all(|id| is_valid(id.digits())));
+            assert!(ids.iter().all(|id| id.get_type() == id_type));
+        }
+    }
+
+    #[test]
+    fn check_digit_of_known_ids() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(id.check_digit(), 1);
+        assert_eq!(id.payload(), [1, 5, 6, 4, 4, 3, 7, 0, 9]);
+        assert_eq!(compute_check_digit(&[1, 5, 6, 4, 4, 3, 7, 0, 9]), 1);
+        assert_eq!(compute_check_digit(&[2, 0, 0, 0, 0, 0, 0, 0, 0]), 6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn check_digit_of_generated_ids() {
+        for id_type in [IdType::Citizen, IdType::Resident] {
+            for id in Id::random_iter(id_type).take(1_000) {
+                let payload = <&[u8; 9]>::try_from(id.payload()).unwrap();
+
+                assert_eq!(compute_check_digit(payload), id.check_digit());
+            }
+        }
+    }
+
+    #[test]
+    fn id_type_from_prefix() {
+        assert_eq!(IdType::from_prefix(1), Some(IdType::Citizen));
+        assert_eq!(IdType::from_prefix(2), Some(IdType::Resident));
+        assert_eq!(IdType::from_prefix(0), None);
+        assert_eq!(IdType::from_prefix(3), None);
+    }
+
+    #[test]
+    fn default_id_type() {
+        assert_eq!(IdType::default(), IdType::Citizen);
+    }
+
+    #[test]
+    fn default_id_type_in_struct() {
+        #[derive(Default)]
+        struct Config {
+            id_type: IdType,
+        }
+
+        assert_eq!(Config::default().id_type, IdType::Citizen);
+    }
+
+    #[test]
+    fn masked_ids() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(id.masked(), "1*******91");
+        assert_eq!(id.masked_with(1, 1, '*'), "1********1");
+        assert_eq!(id.masked_with(1, 4, '#'), "1#####7091");
+        assert_eq!(id.masked_with(3, 0, 'x'), "156xxxxxxx");
+        assert_eq!(id.masked_with(0, 0, '•'), "••••••••••");
+        assert_eq!(id.masked_with(6, 6, '*'), "1564437091");
+        assert_eq!(id.masked_with(20, 20, '*'), "1564437091");
+    }
+
+    #[test]
+    fn id_type_as_map_key() {
+        let mut counts = std::collections::HashMap::new();
+        let id_type = IdType::Resident;
+
+        for id_type in [IdType::Citizen, id_type, id_type] {
+            *counts.entry(id_type).or_insert(0_u32) += 1;
+        }
+
+        assert_eq!(counts[&IdType::Citizen], 1);
+        assert_eq!(counts[&IdType::Resident], 2);
+    }
+
+    #[test]
+    fn id_type_from_prefix_in_const() {
+        const CITIZEN: Option<IdType> = IdType::from_prefix(1);
+        const INVALID: Option<IdType> = IdType::from_prefix(3);
+
+        assert_eq!(CITIZEN, Some(IdType::Citizen));
+        assert_eq!(INVALID, None);
+
+        for id_type in [IdType::Citizen, IdType::Resident] {
+            assert_eq!(IdType::from_prefix(id_type.prefix()), Some(id_type));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn new_with_prefix() {
+        for prefix in [&[1][..], &[2, 5, 0], &[1, 5, 6, 4, 4, 3, 7, 0, 9]] {
+            let id = Id::new_with_prefix(prefix).unwrap();
+
+            assert!(id.digits().starts_with(prefix));
+            assert!(is_valid(id.digits()));
+        }
+        assert_eq!(
+            Id::new_with_prefix(&[1, 5, 6, 4, 4, 3, 7, 0, 9]).unwrap(),
+            Id::from_str("1564437091").unwrap()
+        );
+
+        assert_eq!(
+            Id::new_with_prefix(&[]),
+            Err(ParseError::WrongPrefixLength { got: 0 })
+        );
+        assert_eq!(
+            Id::new_with_prefix(&[1; 10]).unwrap_err().to_string(),
+            "invalid Saudi ID: prefix must have 1 to 9 digits, got 10"
+        );
+        assert_eq!(
+            Id::new_with_prefix(&[3, 1]),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+        assert_eq!(
+            Id::new_with_prefix(&[1, 12]),
+            Err(ParseError::NonDigitCharacter { position: 1 })
+        );
+    }
+
+    #[test]
+    fn valid_count_per_type() {
+        for id_type in [IdType::Citizen, IdType::Resident] {
+            assert_eq!(valid_count(id_type), 100_000_000);
+
+            // Exactly one check digit is valid for each payload.
+            let start = u64::from(id_type.prefix()) * 1_000_000_000;
+            let valid = (start..start + 1_000)
+                .filter(|&n| Id::try_from(n).is_ok())
+                .count();
+            assert_eq!(valid, 100);
+        }
+    }
+
+    #[test]
+    fn as_ref_digits() {
+        fn sum(bytes: impl AsRef<[u8]>) -> u32 {
+            bytes.as_ref().iter().map(|&digit| u32::from(digit)).sum()
+        }
+
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(sum(&id), 40);
+        assert_eq!(sum(id), 40);
+    }
+
+    #[test]
+    fn deref_to_digits() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(id.len(), 10);
+        assert_eq!(id.first(), Some(&1));
+        assert_eq!(id.iter().max(), Some(&9));
+        assert_eq!(&*id, id.digits());
+    }
+
+    #[test]
+    fn iterate_digits() {
+        let id = Id::from_str("1564437091").unwrap();
+        let digits = vec![1, 5, 6, 4, 4, 3, 7, 0, 9, 1];
+
+        let mut borrowed = Vec::new();
+        let mut sum = 0_u32;
+        for &digit in &id {
+            borrowed.push(digit);
+            sum += u32::from(digit);
+        }
+        assert_eq!(borrowed, digits);
+        assert_eq!(sum, 40);
+
+        assert_eq!(id.into_iter().collect::<Vec<u8>>(), digits);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn wipe_zeroes_digits() {
+        let mut id = Id::from_str("1564437091").unwrap();
+        let capacity = id.digits.capacity();
+
+        id.wipe();
+        assert!(id.digits.is_empty());
+
+        // SAFETY: Zeroizing wrote zeros to the whole capacity, so all of it is initialized.
+        unsafe {
+            id.digits.set_len(capacity);
+        }
+        assert!(id.digits.iter().all(|&digit| digit == 0));
+    }
+
+    #[cfg(all(feature = "subtle", feature = "rand"))]
+    #[test]
+    fn validate_ct_agrees_with_is_valid() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+
+        for _ in 0..10_000_u32 {
+            let mut digits = Id::new_with_rng(IdType::Citizen, &mut rng).into_digits();
+
+            // Keep some IDs valid, and break others in a random way.
+            match rng.gen_range(0..4_u8) {
+                0 => {}
+                1 => digits[rng.gen_range(0..ID_SIZE)] = rng.gen_range(0..10),
+                2 => digits[rng.gen_range(0..ID_SIZE)] = rng.gen(),
+                _ => digits.truncate(rng.gen_range(0..ID_SIZE)),
+            }
+
+            assert_eq!(Id::validate_ct(&digits), is_valid(&digits), "{digits:?}");
+        }
+
+        assert!(Id::validate_ct(&[1, 5, 6, 4, 4, 3, 7, 0, 9, 1]));
+        assert!(!Id::validate_ct(&[3, 5, 8, 1, 8, 7, 2, 3, 5, 9]));
+        assert!(!Id::validate_ct(&[1, 5, 6, 4, 4, 3, 7, 0, 19, 1]));
+    }
+
+    #[test]
+    fn compare_with_literals() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(id, 1_564_437_091_u32);
+        assert_eq!(1_564_437_091_u32, id);
+        assert_ne!(id, 1_581_872_353_u32);
+
+        assert_eq!(id, "1564437091");
+        assert_eq!("1 564 437 091", id);
+        assert_ne!(id, "1581872353");
+        assert_ne!(id, "not an ID");
+        assert_ne!(id, "");
+    }
+
+    #[test]
+    fn new_from_digit_source() {
+        struct Counter(u8);
+
+        impl DigitSource for Counter {
+            fn next_digit(&mut self) -> u8 {
+                self.0 += 1;
+                self.0
+            }
+        }
+
+        assert_eq!(
+            Id::new_from_source(IdType::Citizen, &mut Counter(0)),
+            1_123_456_780_u32
+        );
+        // Digits are taken modulo 10.
+        assert_eq!(
+            Id::new_from_source(IdType::Resident, &mut Counter(8)),
+            "2901234563"
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn new_from_rng_digits() {
+        use rand::SeedableRng;
+
+        let mut first = rand::rngs::StdRng::seed_from_u64(5);
+        let mut second = rand::rngs::StdRng::seed_from_u64(5);
+
+        assert_eq!(
+            Id::new_from_source(IdType::Resident, &mut RngDigits(&mut first)),
+            Id::new_with_rng(IdType::Resident, &mut second)
+        );
+    }
+
+    #[test]
+    fn validate_in_const() {
+        const _: () = assert!(validate_const(&[1, 5, 6, 4, 4, 3, 7, 0, 9, 1]));
+        const _: () = assert!(validate_const(&[2, 0, 0, 0, 0, 0, 0, 0, 0, 6]));
+
+        assert!(!validate_const(&[1, 5, 6, 4, 4, 3, 7, 0, 9, 2]));
+        assert!(!validate_const(&[3, 5, 8, 1, 8, 7, 2, 3, 5, 9]));
+        assert!(!validate_const(&[1, 5, 6, 4, 4, 3, 7, 0, 19, 1]));
+    }
+
+    #[test]
+    fn display_keeps_zeros() {
+        assert_eq!(
+            Id::from_str("2000000006").unwrap().to_string(),
+            "2000000006"
+        );
+        assert_eq!(
+            Id::from_str("1000000008").unwrap().to_string(),
+            "1000000008"
+        );
+        assert_eq!(
+            format!("{:#}", Id::from_str("1000000008").unwrap()),
+            "1 000 000 008"
+        );
+    }
+
+    #[test]
+    fn compare_with_str_and_u64() {
+        let id = Id::from_str("1564437091").unwrap();
+        let owned = String::from("1564437091");
+
+        assert!(id == *owned.as_str());
+        assert!(*"1564437091" == id);
+        assert!(id != *"1564437092");
+
+        assert_eq!(id, 1_564_437_091_u64);
+        assert_eq!(1_564_437_091_u64, id);
+        assert_ne!(id, 11_564_437_091_u64);
+    }
+
+    #[test]
+    fn ascii_bytes_round_trip() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert_eq!(&id.to_ascii_bytes(), b"1564437091");
+        assert_eq!(Id::from_ascii_bytes(&id.to_ascii_bytes()).unwrap(), id);
+
+        assert_eq!(
+            Id::from_ascii_bytes(b"2000000006")
+                .unwrap()
+                .to_ascii_bytes(),
+            *b"2000000006"
+        );
+
+        assert_eq!(
+            Id::from_ascii_bytes(&[1, 5, 6, 4, 4, 3, 7, 0, 9, 1]),
+            Err(ParseError::NonDigitCharacter { position: 0 })
+        );
+        assert_eq!(
+            Id::from_ascii_bytes(b"15644370-1"),
+            Err(ParseError::NonDigitCharacter { position: 8 })
+        );
+        assert_eq!(
+            Id::from_ascii_bytes(b"1564437092"),
+            Err(ParseError::FailedLuhn)
+        );
+    }
+
+    #[test]
+    fn signs_are_rejected() {
+        assert_eq!(
+            Id::from_str("+1564437091"),
+            Err(ParseError::NonDigitCharacter { position: 0 })
+        );
+        assert_eq!(
+            Id::from_str("-1564437091"),
+            Err(ParseError::NonDigitCharacter { position: 0 })
+        );
+        assert_eq!(
+            Id::from_str("  -1564437091"),
+            Err(ParseError::NonDigitCharacter { position: 2 })
+        );
+        assert!(!is_valid_str("-1564437091"));
+
+        assert_eq!(Id::from_str(" 1564437091 "), Id::from_str("1564437091"));
+        assert_eq!(Id::from_str("1564437091-"), Id::from_str("1564437091"));
+    }
+
+    #[test]
+    fn accepted_and_rejected_strings() {
+        for accepted in [
+            "1564437091",
+            " 1564437091",
+            "1564437091 ",
+            "\t1564437091\r\n",
+            "1-564-437-091",
+            "1 564 437 091",
+            "15644-37091",
+            "١٥٦٤٤٣٧٠٩١",
+            "۱۵۶۴۴۳۷۰۹۱",
+        ] {
+            assert_eq!(
+                Id::from_str(accepted),
+                Id::from_str("1564437091"),
+                "{accepted:?}"
+            );
+        }
+
+        for (rejected, position) in [
+            ("+1564437091", 0),
+            ("-1564437091", 0),
+            (" +1564437091", 1),
+            ("1564437091\u{a0}", 10),
+            ("1_564_437_091", 1),
+            ("1.564.437.091", 1),
+            ("1564437091/", 10),
+        ] {
+            assert_eq!(
+                Id::from_str(rejected),
+                Err(ParseError::NonDigitCharacter { position }),
+                "{rejected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn build_from_digits() {
+        let mut builder = IdBuilder::new();
+        for digit in [1, 5, 6, 4, 4, 3, 7, 0, 9] {
+            builder.push_digit(digit);
+        }
+
+        // The check digit is computed from the first 9 digits.
+        assert_eq!(builder.finish(), Id::from_str("1564437091"));
+
+        // All 10 digits are validated.
+        assert_eq!(
+            builder.clone().push_digit(1).finish(),
+            Id::from_str("1564437091")
+        );
+        assert_eq!(
+            builder.clone().push_digit(2).finish(),
+            Err(ParseError::FailedLuhn)
+        );
+        assert_eq!(
+            builder.push_digit(1).push_digit(0).finish(),
+            Err(ParseError::WrongBuilderLength { got: 11 })
+        );
+
+        assert_eq!(
+            IdBuilder::new().finish(),
+            Err(ParseError::WrongBuilderLength { got: 0 })
+        );
+        assert_eq!(
+            IdBuilder::new()
+                .push_digit(1)
+                .finish()
+                .unwrap_err()
+                .to_string(),
+            "invalid Saudi ID: expected 9 or 10 digits, got 1"
+        );
+        assert_eq!(
+            IdBuilder::new()
+                .push_digit(3)
+                .push_digit(0)
+                .push_digit(0)
+                .push_digit(0)
+                .push_digit(0)
+                .push_digit(0)
+                .push_digit(0)
+                .push_digit(0)
+                .push_digit(0)
+                .finish(),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+    }
+
+    #[test]
+    fn from_digits_iterator() {
+        assert_eq!(
+            Id::from_digits_iter("1564437091".bytes().map(|byte| byte - b'0')),
+            Id::from_str("1564437091")
+        );
+        assert_eq!(
+            Id::from_digits_iter(Id::from_str("2000000006").unwrap()),
+            Id::from_str("2000000006")
+        );
+        assert_eq!(
+            Id::from_digits_iter(core::iter::repeat_n(1, 3)),
+            Err(ParseError::WrongLength { got: 3 })
+        );
+    }
+
+    #[test]
+    fn from_payload_digits() {
+        assert_eq!(
+            Id::from_payload(IdType::Citizen, &[5, 6, 4, 4, 3, 7, 0, 9]),
+            1_564_437_091_u32
+        );
+        assert_eq!(
+            Id::from_payload(IdType::Resident, &[0; 8]),
+            2_000_000_006_u32
+        );
+    }
+
+    #[test]
+    #[should_panic = "payload digits must be from 0 to 9"]
+    fn from_payload_out_of_range() {
+        drop(Id::from_payload(
+            IdType::Citizen,
+            &[5, 6, 4, 10, 3, 7, 0, 9],
+        ));
+    }
+
+    #[test]
+    fn from_str_distinguishes_failures() {
+        assert_eq!(
+            Id::from_str("abc"),
+            Err(ParseError::NonDigitCharacter { position: 0 })
+        );
+        assert_eq!(Id::from_str("1564437092"), Err(ParseError::FailedLuhn));
+        assert_eq!(
+            Id::from_str("15644"),
+            Err(ParseError::WrongLength { got: 5 })
+        );
+
+        // Same errors as the integer conversion for the same number.
+        assert_eq!(Id::from_str("1564437092"), Id::try_from(1_564_437_092_u32));
+        assert_eq!(Id::from_str("15644"), Id::try_from(15_644_u32));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn changed_digit_is_rejected(id in arbitrary::arb_id(), position in 0..ID_SIZE, digit in 0..11_u8) {
+            let mut digits = id.as_array();
+            let original = digits[position];
+            digits[position] = digit;
+
+            // NOTE: The Luhn Algorithm detects every change of a single digit.
+            let expected = if digit == original {
+                Ok(id)
+            } else if digit > 9 {
+                Err(ParseError::NonDigitCharacter { position })
+            } else if position == 0 && IdType::from_prefix(digit).is_none() {
+                Err(ParseError::InvalidPrefix { got: digit })
+            } else {
+                Err(ParseError::FailedLuhn)
+            };
+
+            proptest::prop_assert_eq!(Id::try_from(digits.to_vec()), Id::try_from(digits));
+            proptest::prop_assert_eq!(Id::try_from(digits), expected);
+        }
+
+        #[test]
+        fn resized_id_is_rejected(id in arbitrary::arb_id(), extra in 0..10_u8) {
+            let mut digits = id.clone().into_digits();
+            let mut truncated = id.to_string();
+            truncated.pop();
+
+            proptest::prop_assert_eq!(
+                Id::try_from(&digits[..ID_SIZE - 1]),
+                Err(ParseError::WrongLength { got: ID_SIZE - 1 })
+            );
+            proptest::prop_assert_eq!(
+                Id::from_str(&truncated),
+                Err(ParseError::WrongLength { got: ID_SIZE - 1 })
+            );
+
+            digits.push(extra);
+            proptest::prop_assert_eq!(
+                Id::try_from(digits),
+                Err(ParseError::WrongLength { got: ID_SIZE + 1 })
+            );
+            proptest::prop_assert_eq!(
+                Id::from_str(&format!("{id}{extra}")),
+                Err(ParseError::WrongLength { got: ID_SIZE + 1 })
+            );
+        }
+
+        #[test]
+        fn accepted_digits_have_a_type(digits in proptest::collection::vec(0..11_u8, 0..12)) {
+            if let Ok(id) = Id::try_from(digits.as_slice()) {
+                proptest::prop_assert!(id.try_get_type().is_ok());
+                proptest::prop_assert_eq!(id.digits(), digits.as_slice());
+            }
+        }
+
+        #[test]
+        fn valid_ids_round_trip(id in arbitrary::arb_id()) {
+            let payload = <[u8; ID_SIZE - 2]>::try_from(&id.payload()[1..]).unwrap();
+
+            proptest::prop_assert_eq!(&Id::from_payload(id.get_type(), &payload), &id);
+            proptest::prop_assert_eq!(Id::try_from(id.as_array()), Ok(id.clone()));
+            proptest::prop_assert_eq!(Id::from_str(&id.to_string()), Ok(id.clone()));
+            proptest::prop_assert_eq!(Id::try_from(id.digits().to_vec()), Ok(id.clone()));
+            proptest::prop_assert_eq!(Id::try_from(id.to_u64()), Ok(id));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_lines_of_reader() {
+        let input = b"1564437091\n1564437092\n\n2 000 000 006\r\n";
+        let results = validate_lines(&input[..])
+            .collect::<std::io::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            results,
+            [
+                (String::from("1564437091"), Id::from_str("1564437091")),
+                (String::from("1564437092"), Err(ParseError::FailedLuhn)),
+                (String::new(), Err(ParseError::WrongLength { got: 0 })),
+                (String::from("2 000 000 006"), Id::from_str("2000000006")),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_lines_with_bad_input() {
+        struct FailingReader;
+
+        impl std::io::Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disconnected"))
+            }
+        }
+
+        let input = b"1564437091\n\xff\n1564437092\n2000000006\n";
+        let results = validate_lines(&input[..])
+            .map(|item| item.map_err(|err| err.kind()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            results,
+            [
+                Ok((String::from("1564437091"), Id::from_str("1564437091"))),
+                Err(std::io::ErrorKind::InvalidData),
+                Ok((String::from("1564437092"), Err(ParseError::FailedLuhn))),
+                Ok((String::from("2000000006"), Id::from_str("2000000006"))),
+            ]
+        );
+
+        let mut results = validate_lines(std::io::BufReader::new(FailingReader));
+        assert_eq!(
+            results.next().unwrap().unwrap_err().kind(),
+            std::io::ErrorKind::Other
+        );
+        assert!(results.next().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_fixed_templates() {
+        let template = [1, 5, 6, 4, 4, 3, 7, 0, 9, 1].map(Some);
+        assert_eq!(Id::from_template(&template), Id::from_str("1564437091"));
+
+        let template = [1, 5, 6, 4, 4, 3, 7, 0, 9, 2].map(Some);
+        assert_eq!(Id::from_template(&template), Err(ParseError::FailedLuhn));
+
+        let mut template = [3, 5, 8, 1, 8, 7, 2, 3, 5, 9].map(Some);
+        assert_eq!(
+            Id::from_template(&template),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+
+        // Only 1 and 2 are tried for a missing prefix.
+        template[0] = None;
+        assert_eq!(Id::from_template(&template), Err(ParseError::FailedLuhn));
+
+        template[4] = Some(10);
+        assert_eq!(
+            Id::from_template(&template),
+            Err(ParseError::NonDigitCharacter { position: 4 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_wild_templates() {
+        let templates = [
+            [
+                Some(1),
+                None,
+                None,
+                Some(5),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ],
+            [
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(7),
+            ],
+            [
+                Some(2),
+                None,
+                Some(0),
+                Some(0),
+                None,
+                Some(9),
+                None,
+                None,
+                Some(3),
+                Some(4),
+            ],
+            [
+                None,
+                Some(5),
+                Some(6),
+                Some(4),
+                Some(4),
+                Some(3),
+                Some(7),
+                Some(0),
+                Some(9),
+                Some(1),
+            ],
+            [None; ID_SIZE],
+        ];
+
+        for template in templates {
+            for _ in 0..100_usize {
+                let id = Id::from_template(&template).unwrap();
+
+                assert!(is_valid(id.digits()));
+                for (digit, fixed) in id.digits().iter().zip(template) {
+                    assert!(fixed.is_none_or(|fixed| fixed == *digit));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn public_id_size() {
+        let buffer = [0_u8; crate::ID_SIZE];
+
+        assert_eq!(buffer.len(), Id::from_str("1564437091").unwrap().len());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn new_citizen_and_resident() {
+        for _ in 0..100_usize {
+            let citizen = Id::new_citizen();
+            let resident = Id::new_resident();
+
+            assert!(citizen.is_citizen() && is_valid(citizen.digits()));
+            assert!(resident.is_resident() && is_valid(resident.digits()));
+        }
+    }
+
+    #[test]
+    fn from_exact_digit_strings() {
+        for s in ["1564437091", "1581872353", "2000000006"] {
+            let id = Id::from_exact_digits(s).unwrap();
+            let expected = s
+                .chars()
+                .map(|c| c.to_digit(10).unwrap())
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                id.digits()
+                    .iter()
+                    .map(|&digit| u32::from(digit))
+                    .collect::<Vec<_>>(),
+                expected
+            );
+        }
+
+        assert_eq!(
+            Id::from_exact_digits("1-564-437-091"),
+            Err(ParseError::NonDigitCharacter { position: 1 })
+        );
+        assert_eq!(
+            Id::from_exact_digits(" 1564437091"),
+            Err(ParseError::NonDigitCharacter { position: 0 })
+        );
+        assert_eq!(
+            Id::from_exact_digits("١٥٦٤٤٣٧٠٩١"),
+            Err(ParseError::NonDigitCharacter { position: 0 })
+        );
+        assert_eq!(
+            Id::from_exact_digits("156443709"),
+            Err(ParseError::WrongLength { got: 9 })
+        );
+        assert_eq!(
+            Id::from_exact_digits("1564437092"),
+            Err(ParseError::FailedLuhn)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn try_new_ids() {
+        for id_type in [IdType::Citizen, IdType::Resident] {
+            let id = Id::try_new(id_type).unwrap();
+
+            assert_eq!(id.get_type(), id_type);
+            assert!(is_valid(id.digits()));
+        }
+    }
+
+    #[test]
+    fn equals_number_and_str() {
+        let id = Id::from_str("1564437091").unwrap();
+
+        assert!(id.equals_number(1_564_437_091));
+        assert!(!id.equals_number(1_564_437_092));
+        assert!(!id.equals_number(0));
+        assert!(!id.equals_number(u64::MAX));
+
+        assert!(id.equals_str("1564437091"));
+        assert!(id.equals_str("1-564-437-091"));
+        assert!(!id.equals_str("1581872353"));
+        assert!(!id.equals_str("1564437092"));
+        assert!(!id.equals_str("not an ID"));
+    }
+
+    #[test]
+    fn metadata() {
+        let metadata = Id::from_str("2581872351").unwrap().metadata();
+
+        assert_eq!(metadata.id_type, IdType::Resident);
+        assert_eq!(metadata.value, 2_581_872_351);
+        assert_eq!(
+            Id::from_str("1564437091").unwrap().metadata().id_type,
+            IdType::Citizen
+        );
+    }
+
+    #[test]
+    fn from_slice_of_larger_buffer() {
+        let buffer = [9, 1, 5, 6, 4, 4, 3, 7, 0, 9, 1, 9];
+
+        assert_eq!(
+            Id::try_from(&buffer[1..11]).unwrap(),
+            Id::from_str("1564437091").unwrap()
+        );
+        assert_eq!(
+            Id::try_from(&buffer[1..10]),
+            Err(ParseError::WrongLength { got: 9 })
+        );
+    }
+
+    #[test]
+    fn unknown_prefix_strict_and_lenient() {
+        assert_eq!(
+            Id::from_str("3581872359"),
+            Err(ParseError::InvalidPrefix { got: 3 })
+        );
+        assert!(!is_valid_str("3581872359"));
+
+        assert_eq!(classify("3581872359"), Ok(Classification::Unknown(3)));
+        assert_eq!(
+            classify("1564437091"),
+            Ok(Classification::Known(IdType::Citizen))
+        );
+        assert_eq!(
+            classify("2000000006"),
+            Ok(Classification::Known(IdType::Resident))
+        );
+        assert_eq!(classify("3581872358"), Err(ParseError::FailedLuhn));
+        assert_eq!(
+            classify("358187235"),
+            Err(ParseError::WrongLength { got: 9 })
+        );
+    }
+
+    #[test]
+    fn id_type_names() {
+        for id_type in [IdType::Citizen, IdType::Resident] {
+            assert_eq!(IdType::from_name(id_type.name()), Some(id_type));
+            assert_eq!(
+                IdType::from_name(&id_type.name().to_uppercase()),
+                Some(id_type)
+            );
+        }
+
+        assert_eq!(IdType::Resident.name(), "resident");
+        assert_eq!(IdType::from_name("visitor"), None);
+        assert_eq!(IdType::from_name(""), None);
     }
 }
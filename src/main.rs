@@ -0,0 +1,115 @@
+//! Command-line tool to validate and generate IDs, enabled by the `cli` feature.
+
+use std::io::BufRead;
+use std::process::ExitCode;
+
+use saudi_id::{Id, IdType};
+
+const USAGE: &str = "\
+usage: saudi_id validate [ID...]
+       saudi_id generate [--type citizen|resident] [--count COUNT]
+       saudi_id --generate citizen|resident COUNT
+       saudi_id < FILE
+
+IDs are read from stdin (one per line) when none are given to validate. Without any arguments, each line of stdin is
+printed as `VALID <type>` or `INVALID`.";
+
+/// How each validated ID is printed.
+#[derive(Clone, Copy)]
+enum Output {
+    /// The ID with its type, or with the error.
+    Detailed,
+    /// `VALID <type>` or `INVALID`.
+    Plain,
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match args.split_first() {
+        None => validate(&[], Output::Plain),
+        Some((command, rest)) if command == "validate" => validate(rest, Output::Detailed),
+        Some((command, rest)) if command == "generate" => generate(rest),
+        Some((flag, rest)) if flag == "--generate" && rest.len() == 2 => {
+            match (IdType::from_name(&rest[0]), rest[1].parse()) {
+                (Some(id_type), Ok(count)) => print_random(id_type, count),
+                _ => usage(),
+            }
+        }
+        _ => usage(),
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("{USAGE}");
+
+    ExitCode::from(2)
+}
+
+/// Print the type of each ID, failing if any of them is invalid.
+fn validate(ids: &[String], output: Output) -> ExitCode {
+    let mut all_valid = true;
+    let mut check = |id: &str| {
+        let result = id.parse::<Id>();
+        all_valid &= result.is_ok();
+
+        match (output, result) {
+            (Output::Detailed, Ok(id)) => println!("{id}: {}", id.get_type().name()),
+            (Output::Detailed, Err(err)) => println!("{id}: {err}"),
+            (Output::Plain, Ok(id)) => println!("VALID {}", id.get_type().name()),
+            (Output::Plain, Err(_)) => println!("INVALID"),
+        }
+    };
+
+    if ids.is_empty() {
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                eprintln!("failed to read stdin");
+                return ExitCode::FAILURE;
+            };
+
+            if !line.trim().is_empty() {
+                check(line.trim());
+            }
+        }
+    } else {
+        for id in ids {
+            check(id);
+        }
+    }
+
+    if all_valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn generate(options: &[String]) -> ExitCode {
+    let mut id_type = IdType::default();
+    let mut count = 1;
+
+    for option in options.chunks(2) {
+        match (option[0].as_str(), option.get(1).map(String::as_str)) {
+            ("--type", Some(name)) => match IdType::from_name(name) {
+                Some(value) => id_type = value,
+                None => return usage(),
+            },
+            ("--count", Some(value)) => match value.parse() {
+                Ok(value) => count = value,
+                Err(_) => return usage(),
+            },
+            _ => return usage(),
+        }
+    }
+
+    print_random(id_type, count)
+}
+
+fn print_random(id_type: IdType, count: usize) -> ExitCode {
+    for id in Id::random_iter(id_type).take(count) {
+        println!("{id}");
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,174 @@
+//! [`serde`] support, enabled by the `serde` feature.
+//!
+//! [`Id`] is serialized as its 10 digits string, and [`IdType`] as `"citizen"` or `"resident"`.
+
+use core::fmt;
+use core::str::FromStr;
+
+use serde::de::{self, Deserializer, Unexpected, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{Id, IdType};
+
+impl Serialize for Id {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Accepts the digits as a string or as an integer, validating them like [`FromStr`] and [`TryFrom<u64>`].
+struct IdVisitor;
+
+impl Visitor<'_> for IdVisitor {
+    type Value = Id;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a Saudi ID as a string or an integer")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Id::from_str(v).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Id::try_from(v).map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Id {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // NOTE: Non self-describing formats (e.g. bincode) can't deserialize_any, and always get a string anyway.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(IdVisitor)
+        } else {
+            deserializer.deserialize_str(IdVisitor)
+        }
+    }
+}
+
+const ID_TYPE_NAMES: &[&str] = &[IdType::Citizen.name(), IdType::Resident.name()];
+
+impl Serialize for IdType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Accepts an [`IdType::name`], ignoring ASCII case.
+struct IdTypeVisitor;
+
+impl Visitor<'_> for IdTypeVisitor {
+    type Value = IdType;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "one of {ID_TYPE_NAMES:?}")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        IdType::from_name(v).ok_or_else(|| E::unknown_variant(v, ID_TYPE_NAMES))
+    }
+}
+
+impl<'de> Deserialize<'de> for IdType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(IdTypeVisitor)
+    }
+}
+
+#[expect(clippy::allow_attributes_without_reason)]
+#[expect(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn json_round_trip() {
+        let id = Id::new(IdType::Citizen);
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, format!("\"{id}\""));
+        assert_eq!(serde_json::from_str::<Id>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn json_from_number() {
+        assert_eq!(
+            serde_json::from_str::<Id>("1564437091").unwrap(),
+            Id::from_str("1564437091").unwrap()
+        );
+        serde_json::from_str::<Id>("-1564437091").unwrap_err();
+    }
+
+    #[test]
+    fn json_rejects_invalid() {
+        let err = serde_json::from_str::<Id>("\"1564437092\"").unwrap_err();
+
+        assert!(err.to_string().contains("failed Luhn validation"));
+        serde_json::from_str::<Id>("1564437092").unwrap_err();
+        serde_json::from_str::<Id>("true").unwrap_err();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn bincode_round_trip() {
+        let id = Id::new(IdType::Resident);
+        let bytes = bincode::serialize(&id).unwrap();
+
+        assert_eq!(bincode::deserialize::<Id>(&bytes).unwrap(), id);
+    }
+
+    #[test]
+    fn id_type_round_trip() {
+        assert_eq!(
+            serde_json::to_string(&IdType::Citizen).unwrap(),
+            "\"citizen\""
+        );
+        assert_eq!(
+            serde_json::from_str::<IdType>("\"resident\"").unwrap(),
+            IdType::Resident
+        );
+        serde_json::from_str::<IdType>("\"visitor\"").unwrap_err();
+
+        let bytes = bincode::serialize(&IdType::Resident).unwrap();
+        assert_eq!(
+            bincode::deserialize::<IdType>(&bytes).unwrap(),
+            IdType::Resident
+        );
+    }
+
+    #[test]
+    fn id_type_ignores_case() {
+        assert_eq!(
+            serde_json::from_str::<IdType>("\"Citizen\"").unwrap(),
+            IdType::Citizen
+        );
+        assert_eq!(
+            serde_json::from_str::<IdType>("\"RESIDENT\"").unwrap(),
+            IdType::Resident
+        );
+    }
+
+    #[test]
+    fn id_type_from_string_value() {
+        use serde::de::{value::StrDeserializer, IntoDeserializer};
+
+        // Plain string values, as config formats like TOML hand them over.
+        let de: StrDeserializer<'_, de::value::Error> = "Resident".into_deserializer();
+        assert_eq!(IdType::deserialize(de).unwrap(), IdType::Resident);
+
+        let de: StrDeserializer<'_, de::value::Error> = "visitor".into_deserializer();
+        let err = IdType::deserialize(de).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown variant `visitor`, expected `citizen` or `resident`"
+        );
+    }
+}
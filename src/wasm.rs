@@ -0,0 +1,88 @@
+//! [`wasm_bindgen`](mod@wasm_bindgen) functions for validating and generating IDs from JavaScript, enabled by the
+//! `wasm` feature.
+//!
+//! The feature only adds this module, so the rest of the crate is the same with it off.
+//!
+//! # Building with `wasm-pack`
+//!
+//! `wasm-pack` needs a `cdylib`, which would break `no_std` builds of this crate, so build a small wrapper crate that
+//! re-exports this module:
+//!
+//! ```toml
+//! [lib]
+//! crate-type = ["cdylib"]
+//!
+//! [dependencies]
+//! saudi_id = { version = "0.2", features = ["wasm"] }
+//! # Only needed for `generate`, so `rand` can get randomness from the browser.
+//! getrandom = { version = "0.2", features = ["js"] }
+//! ```
+//!
+//! With `pub use saudi_id::wasm::*;` in its `src/lib.rs`, then run `wasm-pack build --target web` in it.
+
+use alloc::string::String;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::Id;
+#[cfg(feature = "std")]
+use crate::IdType;
+
+/// Check if `s` is a valid ID, parsed like [`FromStr`](core::str::FromStr).
+#[wasm_bindgen]
+#[must_use]
+pub fn validate(s: &str) -> bool {
+    crate::is_valid_str(s)
+}
+
+/// `"citizen"` or `"resident"` if `s` is a valid ID, `undefined` otherwise.
+#[wasm_bindgen]
+#[must_use]
+pub fn id_type(s: &str) -> Option<String> {
+    Some(String::from(s.parse::<Id>().ok()?.get_type().name()))
+}
+
+/// A new random ID of `id_type` (`"citizen"` or `"resident"`, ignoring ASCII case), `undefined` for other types.
+///
+/// Needs the `std` feature.
+#[cfg(feature = "std")]
+#[wasm_bindgen]
+#[must_use]
+pub fn generate(id_type: &str) -> Option<String> {
+    IdType::from_name(id_type).map(|id_type| Id::new(id_type).into())
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    use super::*;
+
+    #[test]
+    fn validate_strings() {
+        assert!(validate("1564437091"));
+        assert!(validate("2000000006"));
+        assert!(!validate("1564437092"));
+        assert!(!validate(""));
+    }
+
+    #[test]
+    fn id_type_of_strings() {
+        assert_eq!(id_type("1564437091").as_deref(), Some("citizen"));
+        assert_eq!(id_type("2000000006").as_deref(), Some("resident"));
+        assert_eq!(id_type("3581872359"), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[expect(clippy::unwrap_used, reason = "Both types are known")]
+    #[test]
+    fn generate_types() {
+        let citizen = generate("citizen").unwrap();
+        let resident = generate("Resident").unwrap();
+
+        assert_eq!(id_type(&citizen).as_deref(), Some("citizen"));
+        assert_eq!(id_type(&resident).as_deref(), Some("resident"));
+        assert_eq!(generate("visitor"), None);
+    }
+}